@@ -2,8 +2,12 @@
 //! They can be used to defined common UI elements like buttons, forms, and modals. In this template, we define a
 //! RadarGraph component to be used in our app.
 
+pub mod axis_tooltip;
+pub mod color;
 pub mod editable_tooltip;
+pub mod palette;
 pub mod radar;
+pub mod theme;
 pub mod theme_buttons;
 pub mod tooltip;
 pub mod utils;
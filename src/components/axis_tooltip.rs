@@ -0,0 +1,140 @@
+use crate::components::color::Color;
+use crate::components::theme::use_theme;
+use crate::components::tooltip::{format_value, tooltip_layout};
+use crate::components::utils::estimate_width;
+use dioxus::prelude::*;
+
+/// Height of a single row in the tooltip
+const ROW_HEIGHT: f32 = 16.0;
+/// Font size of the title and rows
+const FONT_SIZE: f32 = 12.0;
+/// Space between the box edge and its content
+const PADDING: f32 = 8.0;
+/// Space taken by the color swatch in front of a name
+const SWATCH_WIDTH: f32 = 14.0;
+/// Least space between a name and its value
+const VALUE_GAP: f32 = 16.0;
+
+/// One line of the axis tooltip
+#[derive(Clone, Debug, PartialEq)]
+pub struct AxisTooltipRow {
+    /// Name of the curve
+    pub name: String,
+    /// Value of the curve on the axis
+    pub value: f32,
+    /// Color of the curve, shown as a swatch
+    pub color: Color,
+}
+
+/// Props for the AxisTooltip component
+#[derive(Props, PartialEq, Clone)]
+pub struct AxisTooltipProps {
+    /// The x-coordinate the tooltip points at
+    pub x: f32,
+    /// The y-coordinate the tooltip points at
+    pub y: f32,
+    /// Width of the drawing area, used to keep the tooltip inside
+    pub bounds_width: f32,
+    /// Height of the drawing area, used to keep the tooltip inside
+    pub bounds_height: f32,
+    /// Name of the axis, shown as the title
+    pub title: String,
+    /// One row per curve, in display order
+    pub rows: Vec<AxisTooltipRow>,
+}
+
+/// A tooltip listing the values of several curves on one axis
+#[component]
+pub fn AxisTooltip(props: AxisTooltipProps) -> Element {
+    let theme = use_theme();
+    let height = ROW_HEIGHT * (props.rows.len() + 1) as f32 + PADDING;
+
+    // Wide enough for the title and the longest name and value
+    let values = props
+        .rows
+        .iter()
+        .map(|row| format_value(row.value))
+        .collect::<Vec<_>>();
+    let content_width = props
+        .rows
+        .iter()
+        .zip(&values)
+        .map(|(row, value)| {
+            SWATCH_WIDTH
+                + estimate_width(&row.name, FONT_SIZE)
+                + VALUE_GAP
+                + estimate_width(value, FONT_SIZE)
+        })
+        .fold(estimate_width(&props.title, FONT_SIZE), f32::max);
+    let layout = tooltip_layout(
+        props.x,
+        props.y,
+        content_width + 2.0 * PADDING,
+        height,
+        (props.bounds_width, props.bounds_height),
+    );
+    let (left, top) = (layout.left, layout.top);
+    let rows = props
+        .rows
+        .iter()
+        .zip(values)
+        .enumerate()
+        .map(|(index, (row, value))| {
+            let y = top + PADDING / 2.0 + ROW_HEIGHT * (index + 1) as f32;
+            rsx! {
+                rect {
+                    x: "{left + PADDING}",
+                    y: "{y + 4.0}",
+                    width: "8",
+                    height: "8",
+                    fill: "{row.color}",
+                }
+                text {
+                    x: "{left + PADDING + SWATCH_WIDTH}",
+                    y: "{y + 12.0}",
+                    fill: "{theme.tooltip_text}",
+                    "font-size": "{FONT_SIZE}",
+                    "{row.name}"
+                }
+                text {
+                    x: "{left + layout.width - PADDING}",
+                    y: "{y + 12.0}",
+                    fill: "{theme.tooltip_text}",
+                    "text-anchor": "end",
+                    "font-size": "{FONT_SIZE}",
+                    "{value}"
+                }
+            }
+        });
+
+    rsx! {
+        g {
+            class: "tooltip axis-tooltip",
+            style: "pointer-events: none;",
+            rect {
+                x: "{left}",
+                y: "{top}",
+                width: "{layout.width}",
+                height: "{layout.height}",
+                rx: "3",
+                ry: "3",
+                fill: "{theme.tooltip_background}",
+                opacity: "{theme.tooltip_opacity}",
+            }
+            polygon {
+                points: "{layout.arrow}",
+                fill: "{theme.tooltip_background}",
+                opacity: "{theme.tooltip_opacity}",
+            }
+            text {
+                x: "{left + PADDING}",
+                y: "{top + 16.0}",
+                fill: "{theme.tooltip_text}",
+                "font-size": "{FONT_SIZE}",
+                "font-weight": "600",
+                "{props.title}"
+            }
+            {rows}
+        }
+    }
+}
@@ -0,0 +1,297 @@
+//! Validated color type used for curves, palettes and themes
+//!
+//! Colors are parsed from the CSS notations people usually write by hand
+//! (hex, `rgb()`, `hsl()` and common color names) and always rendered back as
+//! canonical CSS, so only well-formed values ever end up in the markup.
+
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Error returned when a string is not a supported color
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("Invalid color '{value}': expected hex, rgb(), hsl() or a color name")]
+pub struct ColorError {
+    /// The rejected input
+    pub value: String,
+}
+
+/// An sRGB color with alpha
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    /// Opacity from 0.0 (transparent) to 1.0 (opaque)
+    pub a: f32,
+}
+
+/// Neutral gray used when a curve has no color at all
+impl Default for Color {
+    fn default() -> Self {
+        Self::from_hex(0x888888)
+    }
+}
+
+/// Named colors accepted by [`Color::parse`]
+const NAMED_COLORS: &[(&str, u32)] = &[
+    ("black", 0x000000),
+    ("silver", 0xC0C0C0),
+    ("gray", 0x808080),
+    ("grey", 0x808080),
+    ("white", 0xFFFFFF),
+    ("maroon", 0x800000),
+    ("red", 0xFF0000),
+    ("purple", 0x800080),
+    ("fuchsia", 0xFF00FF),
+    ("magenta", 0xFF00FF),
+    ("green", 0x008000),
+    ("lime", 0x00FF00),
+    ("olive", 0x808000),
+    ("yellow", 0xFFFF00),
+    ("navy", 0x000080),
+    ("blue", 0x0000FF),
+    ("teal", 0x008080),
+    ("aqua", 0x00FFFF),
+    ("cyan", 0x00FFFF),
+    ("orange", 0xFFA500),
+    ("brown", 0xA52A2A),
+    ("pink", 0xFFC0CB),
+    ("gold", 0xFFD700),
+    ("indigo", 0x4B0082),
+    ("violet", 0xEE82EE),
+    ("crimson", 0xDC143C),
+    ("coral", 0xFF7F50),
+    ("salmon", 0xFA8072),
+    ("tomato", 0xFF6347),
+    ("turquoise", 0x40E0D0),
+    ("skyblue", 0x87CEEB),
+    ("steelblue", 0x4682B4),
+    ("slategray", 0x708090),
+    ("darkgray", 0xA9A9A9),
+    ("lightgray", 0xD3D3D3),
+];
+
+impl Color {
+    /// Opaque color from its channels
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b, a: 1.0 }
+    }
+
+    /// Opaque color from a `0xRRGGBB` value
+    pub const fn from_hex(hex: u32) -> Self {
+        Self::rgb((hex >> 16) as u8, (hex >> 8) as u8, hex as u8)
+    }
+
+    /// Parse a CSS color: `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`,
+    /// `rgb()`/`rgba()`, `hsl()`/`hsla()` or a color name
+    pub fn parse(value: &str) -> Result<Self, ColorError> {
+        let error = || ColorError {
+            value: value.to_string(),
+        };
+        let normalized = value.trim().to_ascii_lowercase();
+
+        if let Some(hex) = normalized.strip_prefix('#') {
+            return parse_hex(hex).ok_or_else(error);
+        }
+        if let Some(args) = function_args(&normalized, &["rgb", "rgba"]) {
+            return parse_rgb(&args).ok_or_else(error);
+        }
+        if let Some(args) = function_args(&normalized, &["hsl", "hsla"]) {
+            return parse_hsl(&args).ok_or_else(error);
+        }
+        if normalized == "transparent" {
+            return Ok(Self::rgb(0, 0, 0).with_alpha(0.0));
+        }
+        NAMED_COLORS
+            .iter()
+            .find(|(name, _)| *name == normalized)
+            .map(|(_, hex)| Self::from_hex(*hex))
+            .ok_or_else(error)
+    }
+
+    /// Same color with a different opacity
+    pub fn with_alpha(self, alpha: f32) -> Self {
+        Self {
+            a: alpha.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    /// Linear mix with `other`; `amount` 0.0 keeps this color, 1.0 gives `other`
+    pub fn mix(self, other: Color, amount: f32) -> Self {
+        let t = amount.clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        Self {
+            r: channel(self.r, other.r),
+            g: channel(self.g, other.g),
+            b: channel(self.b, other.b),
+            a: self.a + (other.a - self.a) * t,
+        }
+    }
+
+    /// Lighter variant, mixing `amount` (0.0..=1.0) of white in
+    pub fn lighten(self, amount: f32) -> Self {
+        self.mix(Self::rgb(255, 255, 255).with_alpha(self.a), amount)
+    }
+
+    /// Darker variant, mixing `amount` (0.0..=1.0) of black in
+    pub fn darken(self, amount: f32) -> Self {
+        self.mix(Self::rgb(0, 0, 0).with_alpha(self.a), amount)
+    }
+
+    /// Hex notation without alpha, e.g. `#3366cc`
+    pub fn to_hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// Renders as CSS: hex for opaque colors, `rgba()` otherwise
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.a >= 1.0 {
+            f.write_str(&self.to_hex())
+        } else {
+            write!(f, "rgba({}, {}, {}, {})", self.r, self.g, self.b, self.a)
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = ColorError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let digit = |i: usize| u8::from_str_radix(&hex[i..=i], 16).ok();
+    let pair = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+
+    match hex.len() {
+        3 | 4 => {
+            let short = |i: usize| digit(i).map(|d| d * 17);
+            let alpha = if hex.len() == 4 { short(3)? } else { 255 };
+            Some(Color::rgb(short(0)?, short(1)?, short(2)?).with_alpha(alpha as f32 / 255.0))
+        }
+        6 | 8 => {
+            let alpha = if hex.len() == 8 { pair(6)? } else { 255 };
+            Some(Color::rgb(pair(0)?, pair(2)?, pair(4)?).with_alpha(alpha as f32 / 255.0))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the arguments of `name(...)` for any of the given names,
+/// accepting both comma and space separated syntax
+fn function_args(value: &str, names: &[&str]) -> Option<Vec<String>> {
+    let (name, rest) = value.split_once('(')?;
+    if !names.contains(&name.trim()) {
+        return None;
+    }
+    let inner = rest.strip_suffix(')')?;
+    Some(
+        inner
+            .split([',', ' ', '/'])
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .map(str::to_string)
+            .collect(),
+    )
+}
+
+/// Parse a number or percentage, scaling percentages to `full`
+fn parse_component(value: &str, full: f32) -> Option<f32> {
+    let number = match value.strip_suffix('%') {
+        Some(percent) => percent.parse::<f32>().ok()? / 100.0 * full,
+        None => value.parse::<f32>().ok()?,
+    };
+    number.is_finite().then_some(number)
+}
+
+fn parse_alpha(args: &[String]) -> Option<f32> {
+    match args.get(3) {
+        Some(alpha) => parse_component(alpha, 1.0),
+        None => Some(1.0),
+    }
+}
+
+fn parse_rgb(args: &[String]) -> Option<Color> {
+    if !(3..=4).contains(&args.len()) {
+        return None;
+    }
+    let channel =
+        |i: usize| parse_component(&args[i], 255.0).map(|c| c.clamp(0.0, 255.0).round() as u8);
+    Some(Color::rgb(channel(0)?, channel(1)?, channel(2)?).with_alpha(parse_alpha(args)?))
+}
+
+fn parse_hsl(args: &[String]) -> Option<Color> {
+    if !(3..=4).contains(&args.len()) {
+        return None;
+    }
+    let hue = args[0].trim_end_matches("deg").parse::<f32>().ok()?;
+    let saturation = parse_component(&args[1], 1.0)?.clamp(0.0, 1.0);
+    let lightness = parse_component(&args[2], 1.0)?.clamp(0.0, 1.0);
+    if !hue.is_finite() {
+        return None;
+    }
+
+    // Standard HSL to RGB conversion
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let h = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = lightness - chroma / 2.0;
+    let channel = |c: f32| ((c + m) * 255.0).round() as u8;
+    Some(Color::rgb(channel(r), channel(g), channel(b)).with_alpha(parse_alpha(args)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_notations() {
+        let blue = Color::from_hex(0x3366CC);
+        assert_eq!(Color::parse("#3366CC"), Ok(blue));
+        assert_eq!(Color::parse(" #36c "), Ok(blue));
+        assert_eq!(Color::parse("rgb(51, 102, 204)"), Ok(blue));
+        assert_eq!(Color::parse("rgb(51 102 204)"), Ok(blue));
+        assert_eq!(Color::parse("hsl(220, 60%, 50%)"), Ok(blue));
+        assert_eq!(Color::parse("Red"), Ok(Color::rgb(255, 0, 0)));
+        assert_eq!(
+            Color::parse("rgba(51, 102, 204, 0.5)"),
+            Ok(blue.with_alpha(0.5))
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid() {
+        for value in ["", "#12", "#ggg", "rgb(1, 2)", "hsl(a, b, c)", "blurple"] {
+            assert!(Color::parse(value).is_err(), "{value} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_variants_and_display() {
+        let color = Color::rgb(100, 100, 100);
+        assert_eq!(color.lighten(1.0), Color::rgb(255, 255, 255));
+        assert_eq!(color.darken(1.0), Color::rgb(0, 0, 0));
+        assert_eq!(color.to_string(), "#646464");
+        assert_eq!(
+            color.with_alpha(0.25).to_string(),
+            "rgba(100, 100, 100, 0.25)"
+        );
+    }
+}
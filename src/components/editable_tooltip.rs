@@ -1,22 +1,18 @@
+use crate::components::theme::use_theme;
+use crate::components::tooltip::{tooltip_layout, TooltipContent, NOTE_HEIGHT, TOOLTIP_HEIGHT};
+use crate::components::utils::estimate_width;
 use dioxus::prelude::*;
 
 /// Props for the EditableTooltip component
 #[derive(Props, PartialEq, Clone)]
 pub struct EditableTooltipProps {
-    /// The x-coordinate for the tooltip
+    /// The x-coordinate of the point the tooltip belongs to
     pub x: f32,
-    /// The y-coordinate for the tooltip
+    /// The y-coordinate of the point the tooltip belongs to
     pub y: f32,
-    /// Content to display in the tooltip
-    pub content: String,
-    /// Current value being edited
-    pub value: f32,
-    /// Index of the curve (used to detect data point changes)
-    #[props(default = 0)]
-    pub curve_index: usize,
-    /// Index of the data point within the curve (used to detect data point changes)
-    #[props(default = 0)]
-    pub point_index: usize,
+    /// Content to display in the tooltip; curve id and point index are also
+    /// used to detect data point changes
+    pub content: TooltipContent,
     /// Whether the tooltip is visible
     #[props(default = false)]
     pub visible: bool,
@@ -26,8 +22,21 @@ pub struct EditableTooltipProps {
     /// Whether the tooltip is in editing mode
     #[props(default = false)]
     pub editing: bool,
+    /// Whether the value may be edited; read-only tooltips never show the input
+    #[props(default = true)]
+    pub editable: bool,
     /// Color of the associated curve
     pub color: String,
+    /// Width and height of the drawing area the tooltip has to stay inside
+    #[props(optional)]
+    pub bounds: Option<(f32, f32)>,
+    /// Custom renderer for the content outside of editing mode
+    #[props(optional)]
+    pub render: Option<Callback<TooltipContent, Element>>,
+    /// Width and height of the tooltip when `render` is used, since custom
+    /// content can't be measured up front
+    #[props(default = (180.0, 60.0))]
+    pub custom_size: (f32, f32),
     /// Callback when editing starts
     pub on_start_edit: EventHandler<()>,
     /// Callback when editing is completed with new value
@@ -39,24 +48,23 @@ pub struct EditableTooltipProps {
 /// An editable tooltip component that allows inline editing of values when pinned
 #[component]
 pub fn EditableTooltip(props: EditableTooltipProps) -> Element {
+    let theme = use_theme();
+
     if !props.visible {
         return rsx! {};
     }
 
-    // Position the tooltip slightly above the point
-    let tooltip_x = props.x;
-    let tooltip_y = props.y - 15.0;
-
     // State for the current input value - only used during active editing
-    let mut input_value = use_signal(|| props.value.to_string());
+    let mut input_value = use_signal(|| props.content.value.to_string());
 
     // Create a unique key for the current data point to detect switches
-    let current_data_point_key = format!("{}_{}", props.curve_index, props.point_index);
+    let current_data_point_key =
+        format!("{}_{}", props.content.curve_id, props.content.point_index);
     let mut last_data_point_key = use_signal(|| current_data_point_key.clone());
 
     // Reset input value when switching to a different data point
     if current_data_point_key != *last_data_point_key.read() {
-        input_value.set(props.value.to_string());
+        input_value.set(props.content.value.to_string());
         last_data_point_key.set(current_data_point_key);
     }
 
@@ -76,7 +84,7 @@ pub fn EditableTooltip(props: EditableTooltipProps) -> Element {
         let mut input_value = input_value;
         let on_complete_edit = props.on_complete_edit;
         let on_cancel_edit = props.on_cancel_edit;
-        let original_value = props.value;
+        let original_value = props.content.value;
 
         move |evt: KeyboardEvent| {
             match evt.key() {
@@ -106,7 +114,7 @@ pub fn EditableTooltip(props: EditableTooltipProps) -> Element {
         let mut input_value = input_value;
         let on_complete_edit = props.on_complete_edit;
         let on_cancel_edit = props.on_cancel_edit;
-        let original_value = props.value;
+        let original_value = props.content.value;
 
         move |_evt: FocusEvent| {
             // Try to parse the input value
@@ -121,52 +129,83 @@ pub fn EditableTooltip(props: EditableTooltipProps) -> Element {
         }
     };
 
-    // Parse the content to separate name and value
-    let (name, _) = if let Some(colon_pos) = props.content.find(':') {
-        let name = props.content[..colon_pos].trim();
-        let value_part = props.content[colon_pos + 1..].trim();
-        (name, value_part)
+    // Size the box to the content (or the input while editing) and keep it
+    // inside the drawing area
+    let content = &props.content;
+    let summary = content.summary();
+    let is_editing = props.editable && props.editing && props.pinned;
+    let custom = props.render.filter(|_| !is_editing);
+    let (content_width, height) = if is_editing {
+        (estimate_width(&content.axis, 10.0) + 90.0, TOOLTIP_HEIGHT)
+    } else if custom.is_some() {
+        props.custom_size
     } else {
-        (props.content.as_str(), "")
+        let width = content
+            .notes
+            .iter()
+            .map(|note| estimate_width(note, 10.0))
+            .fold(estimate_width(&summary, 12.0), f32::max);
+        (
+            width + 16.0,
+            TOOLTIP_HEIGHT + NOTE_HEIGHT * content.notes.len() as f32,
+        )
     };
+    // Leave room for the pin indicator
+    let width = if props.pinned {
+        content_width + 8.0
+    } else {
+        content_width
+    };
+    let layout = tooltip_layout(
+        props.x,
+        props.y,
+        width,
+        height,
+        props.bounds.unwrap_or((f32::INFINITY, f32::INFINITY)),
+    );
 
     rsx! {
         g {
             class: "editable-tooltip {pinned_class}",
 
-            // Tooltip background
+            // Tooltip background and the arrow pointing at the data point
             rect {
-                x: "{tooltip_x - 60.0}",
-                y: "{tooltip_y - 25.0}",
-                width: "120",
-                height: "20",
+                x: "{layout.left}",
+                y: "{layout.top}",
+                width: "{layout.width}",
+                height: "{layout.height}",
                 rx: "3",
                 ry: "3",
-                fill: "#333333",
-                class: "dark:fill-gray-800",
-                opacity: "0.8",
+                fill: "{theme.tooltip_background}",
+                opacity: "{theme.tooltip_opacity}",
+            }
+            polygon {
+                points: "{layout.arrow}",
+                fill: "{theme.tooltip_background}",
+                opacity: "{theme.tooltip_opacity}",
             }
 
             // Single foreignObject container for all tooltip content
             foreignObject {
-                x: "{tooltip_x - 60.0}",
-                y: "{tooltip_y - 25.0}",
-                width: "120",
-                height: "20",
+                x: "{layout.left}",
+                y: "{layout.top}",
+                width: "{layout.width}",
+                height: "{layout.height}",
 
                 div {
-                    class: "w-full h-full flex items-center justify-center px-2 text-white text-xs",
+                    class: "w-full h-full flex items-center justify-center px-2 text-xs",
+                    style: "color: {theme.tooltip_text};",
 
-                    if props.editing && props.pinned {
+                    if is_editing {
                         // Editing mode: name label + input field
                         div {
                             class: "flex items-center justify-between w-full gap-1",
 
                             // Name label
                             span {
-                                class: "text-white flex-shrink-0",
+                                class: "flex-shrink-0",
                                 style: "font-size: 10px;",
-                                "{name}:"
+                                "{content.axis}:"
                             }
 
                             // Input field
@@ -174,7 +213,7 @@ pub fn EditableTooltip(props: EditableTooltipProps) -> Element {
                                 r#type: "number",
                                 step: "0.1",
                                 value: "{input_value.read()}",
-                                class: "flex-1 min-w-0 px-1 text-center text-white border border-gray-500 rounded outline-none focus:border-blue-400 bg-white bg-opacity-10",
+                                class: "flex-1 min-w-0 px-1 text-center text-inherit border border-gray-500 rounded outline-none focus:border-blue-400 bg-white bg-opacity-10",
                                 style: "font-size: 10px; -moz-appearance: textfield;",
                                 oninput: handle_input,
                                 onkeydown: handle_key_press,
@@ -183,12 +222,20 @@ pub fn EditableTooltip(props: EditableTooltipProps) -> Element {
                                 autofocus: true,
                             }
                         }
+                    } else if let Some(render) = custom {
+                        // Custom content supplied by the user
+                        div {
+                            class: "w-full h-full",
+                            {render.call(content.clone())}
+                        }
                     } else {
-                        // Normal mode: display full content
-                        span {
-                            class: "text-white cursor-pointer text-center",
-                            style: "font-size: 12px;",
-                            "{props.content}"
+                        // Normal mode: value and notes
+                        div {
+                            class: if props.editable { "flex flex-col cursor-pointer text-center" } else { "flex flex-col cursor-default text-center" },
+                            span { style: "font-size: 12px;", "{summary}" }
+                            for note in content.notes.iter() {
+                                span { class: "opacity-80", style: "font-size: 10px;", "{note}" }
+                            }
                         }
                     }
                 }
@@ -197,8 +244,8 @@ pub fn EditableTooltip(props: EditableTooltipProps) -> Element {
             // Visual indicator for pinned tooltips
             if props.pinned && !props.editing {
                 circle {
-                    cx: "{tooltip_x + 45.0}",
-                    cy: "{tooltip_y - 20.0}",
+                    cx: "{layout.left + layout.width - 5.0}",
+                    cy: "{layout.top + 5.0}",
                     r: "2",
                     fill: "{props.color}",
                     class: "tooltip-pin-indicator"
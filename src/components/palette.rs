@@ -0,0 +1,106 @@
+//! Named color palettes used to color curves automatically
+//!
+//! Every palette comes in a light and a dark variant so colors keep enough
+//! contrast against the background of the active theme.
+
+use crate::components::color::Color;
+
+/// A named list of curve colors
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Palette {
+    /// General purpose categorical colors (the classic chart palette)
+    #[default]
+    Categorical,
+    /// Okabe-Ito palette, distinguishable with common color vision deficiencies
+    OkabeIto,
+    /// Muted colors in the style of Tableau 10
+    Tableau,
+    /// Few, strongly contrasting colors for projectors and low vision
+    HighContrast,
+}
+
+impl Palette {
+    /// All colors of the palette for a light or dark background
+    pub fn colors(&self, dark: bool) -> Vec<Color> {
+        self.hex_colors(dark)
+            .iter()
+            .map(|hex| Color::from_hex(*hex))
+            .collect()
+    }
+
+    /// Color for the item at `index`, cycling when the palette runs out
+    pub fn color(&self, index: usize, dark: bool) -> Color {
+        let colors = self.hex_colors(dark);
+        Color::from_hex(colors[index % colors.len()])
+    }
+
+    fn hex_colors(&self, dark: bool) -> &'static [u32] {
+        match (self, dark) {
+            (Palette::Categorical, false) => &[
+                0x3366CC, 0xDC3912, 0xFF9900, 0x109618, 0x990099, 0x0099C6, 0xDD4477, 0x66AA00,
+                0xB82E2E, 0x316395,
+            ],
+            (Palette::Categorical, true) => &[
+                0x6C9BEA, 0xF0694A, 0xFFB84D, 0x3CC14A, 0xCC4DCC, 0x33C3EC, 0xEE7A9C, 0x8FD33A,
+                0xE06666, 0x5C8FD6,
+            ],
+            // Yellow is moved to the end on light backgrounds where it is hard to see
+            (Palette::OkabeIto, false) => &[
+                0xE69F00, 0x56B4E9, 0x009E73, 0x0072B2, 0xD55E00, 0xCC79A7, 0x000000, 0xF0E442,
+            ],
+            // Same order as on light backgrounds, so a curve keeps its hue, with
+            // black replaced by light gray and blue lightened
+            (Palette::OkabeIto, true) => &[
+                0xE69F00, 0x56B4E9, 0x009E73, 0x3A9AD9, 0xD55E00, 0xCC79A7, 0xDDDDDD, 0xF0E442,
+            ],
+            (Palette::Tableau, false) => &[
+                0x4E79A7, 0xF28E2B, 0xE15759, 0x76B7B2, 0x59A14F, 0xEDC948, 0xB07AA1, 0xFF9DA7,
+                0x9C755F, 0xBAB0AC,
+            ],
+            (Palette::Tableau, true) => &[
+                0x6A9BD1, 0xFFA64D, 0xFF7A7C, 0x8FD1CC, 0x7CC46E, 0xF5D866, 0xCC9BC0, 0xFFB8C0,
+                0xBF977F, 0xD4CCC8,
+            ],
+            (Palette::HighContrast, false) => {
+                &[0x000000, 0x0000CC, 0xCC0000, 0x007700, 0x9900CC, 0xCC6600]
+            }
+            (Palette::HighContrast, true) => {
+                &[0xFFFFFF, 0x66B3FF, 0xFF6666, 0x33DD33, 0xDD88FF, 0xFFAA33]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variants_have_same_length() {
+        for palette in [
+            Palette::Categorical,
+            Palette::OkabeIto,
+            Palette::Tableau,
+            Palette::HighContrast,
+        ] {
+            assert_eq!(palette.colors(false).len(), palette.colors(true).len());
+        }
+    }
+
+    #[test]
+    fn test_okabe_ito_keeps_order_in_dark_mode() {
+        let light = Palette::OkabeIto.colors(false);
+        let dark = Palette::OkabeIto.colors(true);
+        let changed = (0..light.len())
+            .filter(|&index| light[index] != dark[index])
+            .collect::<Vec<_>>();
+        assert_eq!(changed, vec![3, 6]);
+    }
+
+    #[test]
+    fn test_color_cycles() {
+        let palette = Palette::HighContrast;
+        let count = palette.colors(false).len();
+        assert_eq!(palette.color(count + 1, false), palette.color(1, false));
+    }
+}
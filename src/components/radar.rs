@@ -3,4 +3,4 @@
 pub mod container;
 
 // Re-export main components used in the app
-pub use container::graph::radar::{DataPoint, RadarCurve};
+pub use container::graph::radar::{CurveId, DataPoint, RadarCurve};
@@ -5,9 +5,27 @@ use thiserror::Error;
 /// Submodules containing components directly used by the container
 pub mod graph;
 pub mod legend;
-
-use graph::radar::{RadarCurve, RadarGraph};
-use legend::RadarLegend;
+pub mod stats;
+pub mod validation;
+pub mod visibility;
+
+use graph::radar::axis::AxisLabelMode;
+use graph::radar::events::{
+    AxisEvent, CurveToggleEvent, PointEvent, PointSelection, TooltipPinEvent, ValueChange,
+};
+use graph::radar::geometry::Direction;
+use graph::radar::labels::ValueLabels;
+use graph::radar::style::FillPattern;
+use graph::radar::{CurveId, RadarCurve, RadarGraph};
+use legend::{LegendPlacement, RadarLegend};
+use stats::StatColumn;
+use validation::{validate_data, ValidationReport};
+use visibility::{merge_visibility, VisibilityPreset};
+
+use crate::components::color::ColorError;
+use crate::components::palette::Palette;
+use crate::components::theme::use_theme;
+use crate::components::tooltip::TooltipContent;
 
 /// Error types for RadarContainer
 #[derive(Error, Debug, Clone)]
@@ -25,6 +43,48 @@ pub enum RadarError {
 
     #[error("No curves provided for radar graph")]
     NoCurvesProvided,
+
+    #[error("Maximum value must be a positive finite number, got {value}")]
+    InvalidMaxValue { value: f32 },
+
+    #[error("Duplicate axis name '{axis_name}'")]
+    DuplicateAxisName { axis_name: String },
+
+    #[error("Duplicate curve name '{curve_name}'")]
+    DuplicateCurveName { curve_name: String },
+
+    #[error("Duplicate curve id '{curve_id}'")]
+    DuplicateCurveId { curve_id: CurveId },
+
+    #[error("Non-finite value {value} in curve '{curve_name}' at point {point_index}")]
+    NonFiniteValue {
+        curve_name: String,
+        point_index: usize,
+        value: f32,
+    },
+
+    #[error("Label mismatch in curve '{curve_name}' at point {point_index}: expected axis '{expected}', got '{actual}'")]
+    LabelMismatch {
+        curve_name: String,
+        point_index: usize,
+        expected: String,
+        actual: String,
+    },
+
+    #[error("Invalid color for curve '{curve_name}': {source}")]
+    InvalidColor {
+        curve_name: String,
+        #[source]
+        source: ColorError,
+    },
+
+    #[error("Value {value} in curve '{curve_name}' at point {point_index} is outside the scale 0..={max_value} and will be clamped")]
+    ValueOutOfScale {
+        curve_name: String,
+        point_index: usize,
+        value: f32,
+        max_value: f32,
+    },
 }
 
 /// Props for the RadarContainer component
@@ -43,6 +103,129 @@ pub struct RadarContainerProps {
     /// Height of the SVG element
     #[props(default = 500)]
     pub height: u32,
+    /// Palette used for curves without an explicit color
+    #[props(default)]
+    pub palette: Palette,
+    /// Assign pattern fills to curves that don't have one, for print and
+    /// colorblind-friendly charts
+    #[props(default = false)]
+    pub auto_patterns: bool,
+    /// Animate value edits and visibility toggles
+    #[props(default = true)]
+    pub animate: bool,
+    /// Let the curves grow from the center when the chart is first shown
+    #[props(default = false)]
+    pub intro_animation: bool,
+    /// Where the legend goes relative to the chart
+    #[props(default)]
+    pub legend_placement: LegendPlacement,
+    /// Statistics shown as sortable columns in the legend
+    #[props(default)]
+    pub legend_stats: Vec<StatColumn>,
+    /// Add a sortable legend column per axis group with the weighted
+    /// average of each curve on the axes of that group
+    #[props(default = true)]
+    pub legend_group_scores: bool,
+    /// Per-axis weights for the score statistic; missing weights count as 1
+    #[props(default)]
+    pub axis_weights: Vec<f32>,
+    /// Sort the legend by an axis when its label is clicked; clicking the
+    /// same label again restores the original order
+    #[props(default = false)]
+    pub sort_legend_by_axis: bool,
+    /// Angle of the first axis in degrees, clockwise from 12 o'clock
+    #[props(default = 0.0)]
+    pub start_angle: f32,
+    /// Direction in which the axes follow each other
+    #[props(default)]
+    pub direction: Direction,
+    /// Angle in degrees the axes are spread over; less than 360 draws a
+    /// partial radar such as a semicircle
+    #[props(default = 360.0)]
+    pub sweep: f32,
+    /// Maximum width of an axis label line; longer labels wrap and the plot
+    /// shrinks so that all labels fit
+    #[props(default = 100.0)]
+    pub axis_label_width: f32,
+    /// Maximum number of lines per axis label, the rest is cut off with an
+    /// ellipsis and shown on hover
+    #[props(default = 2)]
+    pub axis_label_lines: usize,
+    /// Whether axis labels are upright, rotated along their axis or thinned
+    /// out; by default this depends on the number of axes
+    #[props(default)]
+    pub axis_label_mode: AxisLabelMode,
+    /// Optional group of each axis, e.g. "Technical" or "Communication".
+    /// Groups are drawn as shaded sectors with their names in a ring around
+    /// the chart.
+    #[props(default)]
+    pub axis_groups: Vec<Option<String>>,
+    /// Write each curve's name next to the curve inside the chart
+    #[props(default = false)]
+    pub direct_labels: bool,
+    /// Write the values next to the data points: for all curves, only the
+    /// focused one or only the extremes of each axis
+    #[props(default)]
+    pub value_labels: ValueLabels,
+    /// Custom renderer for data point tooltips, e.g. to show the previous
+    /// value, the delta to a baseline or a link. Editing still uses the
+    /// built-in input.
+    #[props(optional)]
+    pub render_tooltip: Option<Callback<TooltipContent, Element>>,
+    /// Width and height of tooltips drawn by `render_tooltip`
+    #[props(default = (180.0, 60.0))]
+    pub tooltip_size: (f32, f32),
+    /// Whether values may be edited; set to false for presentation dashboards.
+    /// Individual curves can be locked via [`RadarCurve::editable`].
+    #[props(default = true)]
+    pub editable: bool,
+    /// Whether the parent owns the data.
+    ///
+    /// In controlled mode the container never modifies its own copy of the
+    /// curves, the visibility or the presets; edits, legend toggles and saved
+    /// views are only reported through the change callbacks and show up once
+    /// the parent passes the updated props back in. In uncontrolled mode (the
+    /// default) the container applies the changes itself and still notifies
+    /// the callbacks.
+    #[props(default = false)]
+    pub controlled: bool,
+    /// Visibility of the curves by id; curves that are missing are visible
+    #[props(default)]
+    pub visibility: Vec<(CurveId, bool)>,
+    /// Saved visibility presets offered below the legend
+    #[props(default)]
+    pub presets: Vec<VisibilityPreset>,
+    /// Called with the complete updated curve list after a value was edited
+    #[props(optional)]
+    pub on_curves_change: Option<EventHandler<Vec<RadarCurve>>>,
+    /// Called with the complete updated visibility map after a legend toggle,
+    /// solo, show/hide all or applying a preset
+    #[props(optional)]
+    pub on_visibility_change: Option<EventHandler<Vec<(CurveId, bool)>>>,
+    /// Called with the complete preset list after a preset was saved or removed
+    #[props(optional)]
+    pub on_presets_change: Option<EventHandler<Vec<VisibilityPreset>>>,
+    /// Called when the pinned data point changes (`None` when unpinned)
+    #[props(optional)]
+    pub on_selection_change: Option<EventHandler<Option<PointSelection>>>,
+    /// Called when the pointer enters (`Some`) or leaves (`None`) a data point
+    #[props(optional)]
+    pub on_point_hover: Option<EventHandler<Option<PointEvent>>>,
+    /// Called when a data point is clicked
+    #[props(optional)]
+    pub on_point_click: Option<EventHandler<PointEvent>>,
+    /// Called when a curve is shown or hidden via the legend
+    #[props(optional)]
+    pub on_curve_toggle: Option<EventHandler<CurveToggleEvent>>,
+    /// Called when a curve gets focused by clicking it (`None` when unfocused)
+    #[props(optional)]
+    pub on_focus_change: Option<EventHandler<Option<CurveId>>>,
+    /// Called when an axis label is clicked
+    #[props(optional)]
+    pub on_axis_label_click: Option<EventHandler<AxisEvent>>,
+    /// Called when a tooltip gets pinned or unpinned
+    #[props(optional)]
+    pub on_tooltip_pin_change: Option<EventHandler<TooltipPinEvent>>,
 }
 
 #[allow(dead_code)]
@@ -55,34 +238,50 @@ impl RadarContainerProps {
         width: Option<u32>,
         height: Option<u32>,
     ) -> Result<Self, RadarError> {
-        // Validate axes and curves
-        if axes.is_empty() {
-            return Err(RadarError::NoAxesProvided);
-        }
-
-        if curves.is_empty() {
-            return Err(RadarError::NoCurvesProvided);
-        }
-
-        // Validate that each curve has the correct number of data points
-        for curve in &curves {
-            let data_point_count = curve.data_points.len();
-            if data_point_count != axes.len() {
-                return Err(RadarError::DataPointCountMismatch {
-                    curve_name: curve.name.clone(),
-                    expected: axes.len(),
-                    actual: data_point_count,
-                });
-            }
-        }
+        let max_value = max_value.unwrap_or(100.0);
+        validate_data(&axes, &curves, max_value).into_result()?;
 
         // If validation passes, create the props
         Ok(Self {
             axes,
             curves,
-            max_value: max_value.unwrap_or(100.0),
+            max_value,
             width: width.unwrap_or(600),
             height: height.unwrap_or(500),
+            palette: Palette::default(),
+            auto_patterns: false,
+            animate: true,
+            intro_animation: false,
+            legend_placement: LegendPlacement::default(),
+            legend_stats: Vec::new(),
+            legend_group_scores: true,
+            axis_weights: Vec::new(),
+            sort_legend_by_axis: false,
+            start_angle: 0.0,
+            direction: Direction::default(),
+            sweep: 360.0,
+            axis_label_width: 100.0,
+            axis_label_lines: 2,
+            axis_label_mode: AxisLabelMode::default(),
+            axis_groups: Vec::new(),
+            direct_labels: false,
+            value_labels: ValueLabels::default(),
+            render_tooltip: None,
+            tooltip_size: (180.0, 60.0),
+            editable: true,
+            controlled: false,
+            visibility: Vec::new(),
+            presets: Vec::new(),
+            on_curves_change: None,
+            on_visibility_change: None,
+            on_presets_change: None,
+            on_selection_change: None,
+            on_point_hover: None,
+            on_point_click: None,
+            on_curve_toggle: None,
+            on_focus_change: None,
+            on_axis_label_click: None,
+            on_tooltip_pin_change: None,
         })
     }
 
@@ -92,26 +291,7 @@ impl RadarContainerProps {
         axes: Vec<String>,
         curves: Vec<RadarCurve>,
     ) -> Result<(), RadarError> {
-        // Validate axes and curves
-        if axes.is_empty() {
-            return Err(RadarError::NoAxesProvided);
-        }
-
-        if curves.is_empty() {
-            return Err(RadarError::NoCurvesProvided);
-        }
-
-        // Validate that each curve has the correct number of data points
-        for curve in &curves {
-            let data_point_count = curve.data_points.len();
-            if data_point_count != axes.len() {
-                return Err(RadarError::DataPointCountMismatch {
-                    curve_name: curve.name.clone(),
-                    expected: axes.len(),
-                    actual: data_point_count,
-                });
-            }
-        }
+        validate_data(&axes, &curves, self.max_value).into_result()?;
 
         // If validation passes, update the props
         self.axes = axes;
@@ -119,6 +299,11 @@ impl RadarContainerProps {
         Ok(())
     }
 
+    /// Validate the whole dataset and return every error and warning found
+    pub fn validate(&self) -> ValidationReport {
+        validate_data(&self.axes, &self.curves, self.max_value)
+    }
+
     /// Get a reference to the axes
     pub fn axes(&self) -> &Vec<String> {
         &self.axes
@@ -128,6 +313,53 @@ impl RadarContainerProps {
     pub fn curves(&self) -> &Vec<RadarCurve> {
         &self.curves
     }
+
+    /// Compare only the data carried by the props, ignoring the callbacks.
+    ///
+    /// Callbacks are recreated on every parent render, so they must not cause
+    /// the container to throw away its state.
+    pub fn same_data(&self, other: &Self) -> bool {
+        self.axes == other.axes
+            && self.curves == other.curves
+            && self.max_value == other.max_value
+            && self.width == other.width
+            && self.height == other.height
+            && self.palette == other.palette
+            && self.auto_patterns == other.auto_patterns
+            && self.animate == other.animate
+            && self.intro_animation == other.intro_animation
+            && self.legend_placement == other.legend_placement
+            && self.legend_stats == other.legend_stats
+            && self.legend_group_scores == other.legend_group_scores
+            && self.axis_weights == other.axis_weights
+            && self.sort_legend_by_axis == other.sort_legend_by_axis
+            && self.start_angle == other.start_angle
+            && self.direction == other.direction
+            && self.sweep == other.sweep
+            && self.axis_label_width == other.axis_label_width
+            && self.axis_label_lines == other.axis_label_lines
+            && self.axis_label_mode == other.axis_label_mode
+            && self.axis_groups == other.axis_groups
+            && self.direct_labels == other.direct_labels
+            && self.value_labels == other.value_labels
+            && self.tooltip_size == other.tooltip_size
+            && self.editable == other.editable
+            && self.controlled == other.controlled
+            && self.visibility == other.visibility
+            && self.presets == other.presets
+    }
+
+    /// Look up the current position of a curve by its id.
+    ///
+    /// Helps callers migrating from index-based addressing.
+    pub fn curve_index(&self, id: &CurveId) -> Option<usize> {
+        self.curves.iter().position(|curve| &curve.id == id)
+    }
+
+    /// Look up a curve by its id
+    pub fn curve(&self, id: &CurveId) -> Option<&RadarCurve> {
+        self.curves.iter().find(|curve| &curve.id == id)
+    }
 }
 
 /// A responsive container for the radar graph and legend
@@ -135,149 +367,418 @@ impl RadarContainerProps {
 /// This component handles the responsive layout of the radar graph and legend,
 /// placing them side by side on larger screens and stacking them on smaller screens.
 /// It also manages the visibility state shared between the graph and legend.
+///
+/// By default the container owns a copy of the data (uncontrolled mode). Set
+/// `controlled` to let the parent own curves and visibility instead; either way
+/// the container re-syncs whenever the parent passes different data.
 #[component]
 pub fn RadarContainer(props: RadarContainerProps) -> Element {
+    let theme = use_theme();
+    let controlled = props.controlled;
+    let on_curves_change = props.on_curves_change;
+    let on_visibility_change = props.on_visibility_change;
+    let on_presets_change = props.on_presets_change;
+    let on_selection_change = props.on_selection_change;
+    let on_curve_toggle = props.on_curve_toggle;
+    let on_focus_change = props.on_focus_change;
+    let on_axis_label_click = props.on_axis_label_click;
+
     // Create mutable signal from props so changes to them will trigger re-renders
     // and allow for data editing
-    let mut props_signal = use_signal(|| props);
+    let mut props_signal = use_signal(|| props.clone());
 
     // Initialize visibility state for all curves
-    let mut visible_map = use_signal(|| {
-        // Start with all curves visible (true)
-        props_signal
-            .read()
-            .curves()
-            .iter()
-            .map(|curve| (curve.name.clone(), true))
-            .collect::<Vec<_>>()
-    });
+    let mut visible_map =
+        use_signal(|| merge_visibility(props_signal.read().curves(), &props.visibility));
+
+    // Saved visibility presets
+    let mut presets = use_signal(|| props.presets.clone());
+
+    // Re-sync with the parent whenever it passes different data
+    let mut synced_props = use_signal(|| props.clone());
+    if !synced_props.peek().same_data(&props) {
+        if synced_props.peek().visibility != props.visibility {
+            visible_map.set(merge_visibility(props.curves(), &props.visibility));
+        }
+        if synced_props.peek().presets != props.presets {
+            presets.set(props.presets.clone());
+        }
+        // Only new axes or curves replace the data; a change of display
+        // options alone keeps the values the user edited
+        let mut next = props.clone();
+        let data_changed =
+            synced_props.peek().axes != props.axes || synced_props.peek().curves != props.curves;
+        if !data_changed {
+            let current = props_signal.peek();
+            next.axes = current.axes.clone();
+            next.curves = current.curves.clone();
+        }
+        synced_props.set(props.clone());
+        props_signal.set(next);
+    }
 
     // Update visibility map if curves have changed
     {
-        let current_curve_names: Vec<String> = props_signal
+        let current_curve_ids: Vec<CurveId> = props_signal
             .read()
             .curves()
             .iter()
-            .map(|c| c.name.clone())
+            .map(|c| c.id.clone())
             .collect();
-        let existing_names: Vec<String> = visible_map
+        let existing_ids: Vec<CurveId> = visible_map
             .read()
             .iter()
-            .map(|(name, _)| name.clone())
+            .map(|(id, _)| id.clone())
             .collect();
 
         // If the curves have changed, update the visibility state
-        if current_curve_names != existing_names {
-            let mut new_visibility = Vec::new();
-
-            for curve in props_signal.read().curves() {
-                // Try to find existing visibility setting
-                let is_visible = visible_map
-                    .read()
-                    .iter()
-                    .find(|(name, _)| name == &curve.name)
-                    .map(|(_, vis)| *vis)
-                    .unwrap_or(true); // Default to visible for new curves
-
-                new_visibility.push((curve.name.clone(), is_visible));
-            }
-
+        if current_curve_ids != existing_ids {
+            let new_visibility =
+                merge_visibility(props_signal.read().curves(), &visible_map.read());
             visible_map.set(new_visibility);
         }
     }
 
+    // Every visibility change goes through here, so toggles, solo, bulk
+    // actions and presets report the same events
+    let mut apply_visibility = move |new_map: Vec<(CurveId, bool)>| {
+        let changes = visibility::changes(&visible_map.peek(), &new_map);
+        if changes.is_empty() {
+            return;
+        }
+        if let Some(handler) = &on_curve_toggle {
+            for (curve_id, visible) in changes {
+                handler.call(CurveToggleEvent { curve_id, visible });
+            }
+        }
+        if let Some(handler) = &on_visibility_change {
+            handler.call(new_map.clone());
+        }
+        if !controlled {
+            visible_map.set(new_map);
+        }
+    };
+
     // Handle legend click to toggle visibility
-    let on_legend_click = move |name: String| {
-        let current_map = visible_map.read().clone();
-        let mut new_map = Vec::new();
+    let on_legend_click = move |clicked_id: CurveId| {
+        apply_visibility(visibility::toggle(&visible_map.peek(), &clicked_id));
+    };
 
-        for (curve_name, is_visible) in current_map {
-            if curve_name == name {
-                new_map.push((curve_name, !is_visible)); // Toggle this curve
-            } else {
-                new_map.push((curve_name, is_visible)); // Keep state for others
-            }
+    // Group headers show all curves of the group, or hide them if any is shown
+    let on_group_toggle = move |group: String| {
+        let members = props_signal
+            .peek()
+            .curves()
+            .iter()
+            .filter(|curve| curve.group.as_deref() == Some(group.as_str()))
+            .map(|curve| curve.id.clone())
+            .collect::<Vec<_>>();
+        apply_visibility(visibility::toggle_many(&visible_map.peek(), &members));
+    };
+
+    // Alt-click shows only the clicked curve
+    let on_legend_solo = move |clicked_id: CurveId| {
+        apply_visibility(visibility::solo(&visible_map.peek(), &clicked_id));
+    };
+
+    let mut update_presets = move |new_presets: Vec<VisibilityPreset>| {
+        if let Some(handler) = &on_presets_change {
+            handler.call(new_presets.clone());
+        }
+        if !controlled {
+            presets.set(new_presets);
         }
+    };
 
-        visible_map.set(new_map);
+    // Hovering a curve or its legend entry highlights it temporarily, clicking
+    // the curve toggles a focus that stays until it is clicked again
+    let mut hovered_curve = use_signal(|| None::<CurveId>);
+    let mut focused_curve = use_signal(|| None::<CurveId>);
+    let on_curve_hover = move |curve_id: Option<CurveId>| hovered_curve.set(curve_id);
+    let on_curve_click = move |clicked_id: CurveId| {
+        let focused = (focused_curve.peek().as_ref() != Some(&clicked_id)).then_some(clicked_id);
+        if let Some(handler) = &on_focus_change {
+            handler.call(focused.clone());
+        }
+        focused_curve.set(focused);
+    };
+    let highlighted = hovered_curve
+        .read()
+        .clone()
+        .or(focused_curve.read().clone());
+
+    // Clicking an axis label can rank the legend by that axis
+    let mut legend_sort_axis = use_signal(|| None::<usize>);
+    let handle_axis_label_click = move |event: AxisEvent| {
+        if props_signal.peek().sort_legend_by_axis {
+            let axis =
+                (*legend_sort_axis.peek() != Some(event.axis_index)).then_some(event.axis_index);
+            legend_sort_axis.set(axis);
+        }
+        if let Some(handler) = &on_axis_label_click {
+            handler.call(event);
+        }
     };
 
     // Handle value changes from tooltip editing
-    let handle_value_change = move |change: (usize, usize, f32)| {
-        let (curve_index, point_index, new_value) = change;
+    let handle_value_change = move |change: ValueChange| {
         let mut current_props = props_signal.read().clone();
 
-        // Update the data point value
-        if let Some(curve) = current_props.curves.get_mut(curve_index) {
-            if let Some(data_point) = curve.data_points.get_mut(point_index) {
-                data_point.value = new_value;
-                props_signal.set(current_props);
+        // Update the data point value, looking the curve up by id since it
+        // is stable regardless of ordering and visibility
+        if let Some(curve) = current_props
+            .curves
+            .iter_mut()
+            .find(|curve| curve.id == change.curve_id)
+        {
+            if let Some(data_point) = curve.data_points.get_mut(change.point_index) {
+                data_point.value = change.value;
+
+                if let Some(handler) = &on_curves_change {
+                    handler.call(current_props.curves.clone());
+                }
+                if !controlled {
+                    props_signal.set(current_props);
+                }
             }
         }
     };
 
-    // Filter curves based on visibility for the graph
-    let visible_curves = {
-        let props_read = props_signal.read();
-        props_read
-            .curves()
-            .iter()
-            .filter_map(|curve| {
-                let is_visible = visible_map
-                    .read()
-                    .iter()
-                    .find(|(name, _)| name == &curve.name)
-                    .map(|(_, vis)| *vis)
-                    .unwrap_or(true);
-
-                if is_visible {
-                    Some(curve.clone())
-                } else {
-                    None
+    // Fill in colors from the palette for curves that don't specify one
+    let mut display_curves = with_palette_colors(
+        props_signal.read().curves(),
+        props_signal.read().palette,
+        theme.is_dark,
+    );
+    if props_signal.read().auto_patterns {
+        display_curves = with_auto_patterns(&display_curves);
+    }
+
+    // Hidden curves stay in the graph so they can fade out instead of vanishing
+    let hidden_curves = visible_map
+        .read()
+        .iter()
+        .filter(|(_, is_visible)| !is_visible)
+        .map(|(id, _)| id.clone())
+        .collect::<Vec<_>>();
+
+    let preset_buttons = presets
+        .read()
+        .clone()
+        .into_iter()
+        .enumerate()
+        .map(|(index, preset)| {
+            let label = preset.name.clone();
+            rsx! {
+                div {
+                    key: "{index}-{label}",
+                    class: "flex items-center gap-1",
+                    button {
+                        class: "text-xs px-2 py-1 rounded border flex-1 text-left",
+                        title: "Apply this view",
+                        onclick: move |_| {
+                            let curves = props_signal.peek().curves().clone();
+                            apply_visibility(merge_visibility(&curves, &preset.visibility));
+                        },
+                        "{label}"
+                    }
+                    button {
+                        class: "text-xs px-1 opacity-70",
+                        title: "Remove this view",
+                        onclick: move |_| {
+                            let mut new_presets = presets.peek().clone();
+                            new_presets.remove(index);
+                            update_presets(new_presets);
+                        },
+                        "×"
+                    }
+                }
+            }
+        });
+
+    let placement = props_signal.read().legend_placement;
+    let legend = rsx! {
+        RadarLegend {
+            curves: display_curves.clone(),
+            visible_map: visible_map,
+            on_click: on_legend_click,
+            on_solo: on_legend_solo,
+            on_group_toggle: on_group_toggle,
+            // Long curve lists scroll instead of growing the card
+            max_height: if placement == LegendPlacement::Overlay { 200 } else { 320 },
+            filterable: placement != LegendPlacement::Overlay,
+            highlighted: highlighted,
+            on_hover: on_curve_hover,
+            layout: placement.layout(),
+            stats: props_signal.read().legend_stats.clone(),
+            max_value: props_signal.read().max_value,
+            sweep: props_signal.read().sweep,
+            weights: props_signal.read().axis_weights.clone(),
+            axis_groups: if props_signal.read().legend_group_scores {
+                props_signal.read().axis_groups.clone()
+            } else {
+                Vec::new()
+            },
+            sort_axis: legend_sort_axis(),
+            on_sort_axis_clear: move |_| legend_sort_axis.set(None),
+        }
+    };
+
+    // Card with the legend, bulk visibility controls and saved views
+    let legend_card = rsx! {
+        div {
+            class: "p-4 flex-shrink-0",
+            div {
+                class: "p-4 rounded-lg shadow-md",
+                style: "background-color: {theme.surface};",
+                if matches!(placement, LegendPlacement::Left | LegendPlacement::Right) {
+                    h3 {
+                        class: "text-lg font-semibold mb-2",
+                        "Legend"
+                    }
+                }
+                div {
+                    class: "space-y-2",
+                    {legend.clone()}
+                }
+                div {
+                    class: "flex gap-2 mt-2",
+                    button {
+                        class: "text-xs px-2 py-1 rounded border",
+                        onclick: move |_| apply_visibility(visibility::set_all(&visible_map.peek(), true)),
+                        "Show all"
+                    }
+                    button {
+                        class: "text-xs px-2 py-1 rounded border",
+                        onclick: move |_| apply_visibility(visibility::set_all(&visible_map.peek(), false)),
+                        "Hide all"
+                    }
+                    button {
+                        class: "text-xs px-2 py-1 rounded border",
+                        title: "Save the current visibility as a view",
+                        onclick: move |_| {
+                            let mut new_presets = presets.peek().clone();
+                            let name = visibility::preset_name(&new_presets);
+                            new_presets.push(VisibilityPreset::new(name, visible_map.peek().clone()));
+                            update_presets(new_presets);
+                        },
+                        "Save view"
+                    }
                 }
-            })
-            .collect::<Vec<_>>()
+                div {
+                    class: "flex flex-col gap-1 mt-2",
+                    {preset_buttons}
+                }
+            }
+        }
     };
 
+    // The legend comes after the graph in the markup; the flex direction
+    // decides on which side it ends up
+    let layout_class = match placement {
+        LegendPlacement::Right => "flex flex-col md:flex-row",
+        LegendPlacement::Left => "flex flex-col-reverse md:flex-row-reverse",
+        LegendPlacement::Top => "flex flex-col-reverse",
+        LegendPlacement::Bottom | LegendPlacement::Hidden | LegendPlacement::Overlay => {
+            "flex flex-col"
+        }
+    };
+    let overlay = (placement == LegendPlacement::Overlay).then(|| {
+        rsx! {
+            div {
+                class: "m-2 p-2 rounded-lg shadow-md text-sm",
+                style: "background-color: {theme.surface.with_alpha(0.9)}; color: {theme.text};",
+                {legend.clone()}
+            }
+        }
+    });
+
     rsx! {
         div {
-            // Responsive container that changes from column on small screens to row on medium and larger screens
-            class: "flex flex-col md:flex-row justify-center items-center md:items-start gap-4",
+            class: "{layout_class} justify-center items-center md:items-start gap-4",
 
             // Graph container
             div {
                 class: "flex-shrink-0",
                 RadarGraph {
                     axes: props_signal.read().axes().clone(),
-                    curves: visible_curves,
+                    curves: display_curves.clone(),
+                    hidden_curves: hidden_curves,
                     max_value: props_signal.read().max_value,
                     width: props_signal.read().width,
                     height: props_signal.read().height,
+                    editable: props_signal.read().editable,
+                    animate: props_signal.read().animate,
+                    intro_animation: props_signal.read().intro_animation,
+                    start_angle: props_signal.read().start_angle,
+                    direction: props_signal.read().direction,
+                    sweep: props_signal.read().sweep,
+                    axis_label_width: props_signal.read().axis_label_width,
+                    axis_label_lines: props_signal.read().axis_label_lines,
+                    axis_label_mode: props_signal.read().axis_label_mode,
+                    axis_groups: props_signal.read().axis_groups.clone(),
+                    direct_labels: props_signal.read().direct_labels,
+                    value_labels: props_signal.read().value_labels,
+                    overlay: overlay,
+                    render_tooltip: props.render_tooltip,
+                    tooltip_size: props_signal.read().tooltip_size,
+                    highlighted_curve: hovered_curve.read().clone(),
+                    focused_curve: focused_curve.read().clone(),
                     on_value_change: handle_value_change,
+                    on_selection_change: move |selection| {
+                        if let Some(handler) = &on_selection_change {
+                            handler.call(selection);
+                        }
+                    },
+                    on_point_hover: props.on_point_hover,
+                    on_point_click: props.on_point_click,
+                    // Labels only look clickable when a click does something
+                    on_axis_label_click: (props_signal.read().sort_legend_by_axis || on_axis_label_click.is_some())
+                        .then(|| EventHandler::new(handle_axis_label_click)),
+                    on_tooltip_pin_change: props.on_tooltip_pin_change,
+                    on_curve_hover: on_curve_hover,
+                    on_curve_click: on_curve_click,
                 }
             }
 
-            // Legend container - positioned below on mobile, to the right on desktop
-            div {
-                class: "p-4 flex-shrink-0",
-                div {
-                    class: "bg-white dark:bg-gray-800 p-4 rounded-lg shadow-md",
-                    h3 {
-                        class: "text-lg font-semibold mb-2",
-                        "Legend"
-                    }
-                    div {
-                        class: "space-y-2",
-                        RadarLegend {
-                            curves: props_signal.read().curves().clone(),
-                            visible_map: visible_map,
-                            on_click: on_legend_click,
-                            // Always use vertical layout for the legend
-                            layout: "vertical".to_string(),
-                        }
-                    }
-                }
+            if !matches!(placement, LegendPlacement::Hidden | LegendPlacement::Overlay) {
+                {legend_card}
             }
         }
     }
 }
+
+/// Return the curves with every missing color replaced by a palette color.
+///
+/// Colors are picked by the position in the full curve list, so hiding a
+/// curve does not change the colors of the others.
+pub fn with_palette_colors(curves: &[RadarCurve], palette: Palette, dark: bool) -> Vec<RadarCurve> {
+    curves
+        .iter()
+        .enumerate()
+        .map(|(index, curve)| {
+            let mut curve = curve.clone();
+            if curve.color.is_none() {
+                curve.color = Some(palette.color(index, dark));
+            }
+            curve
+        })
+        .collect()
+}
+
+/// Return the curves with a pattern fill assigned to every curve without one.
+///
+/// Like the palette, patterns are picked by position in the full curve list.
+pub fn with_auto_patterns(curves: &[RadarCurve]) -> Vec<RadarCurve> {
+    curves
+        .iter()
+        .enumerate()
+        .map(|(index, curve)| {
+            let mut curve = curve.clone();
+            if curve.style.pattern.is_none() {
+                curve.style.pattern = Some(FillPattern::ALL[index % FillPattern::ALL.len()]);
+            }
+            curve
+        })
+        .collect()
+}
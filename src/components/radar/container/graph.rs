@@ -1,5 +1,6 @@
 //! Radar graph module
 
+pub mod lollipop;
 pub mod radar;
 
 // No need to re-export from radar's submodules since they're only used internally
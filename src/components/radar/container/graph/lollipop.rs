@@ -0,0 +1,222 @@
+//! Fallback chart for data with too few axes to span a radar
+
+use super::radar::axis::AxisLabel;
+use super::radar::curve::data_point::DataPoint;
+use super::radar::events::PointEvent;
+use super::radar::style::MarkerShape;
+use super::radar::{CurveId, RadarCurve, TooltipData};
+use crate::components::theme::use_theme;
+use crate::components::tooltip::format_value;
+use dioxus::prelude::*;
+
+/// Space taken by the notice above the chart
+const NOTICE_HEIGHT: f32 = 28.0;
+/// Widest the axis label column gets
+const MAX_LABEL_WIDTH: f32 = 120.0;
+/// Space right of the longest stem for the value
+const VALUE_WIDTH: f32 = 40.0;
+/// Distance between the rows of two curves on the same axis
+const MAX_ROW_HEIGHT: f32 = 24.0;
+/// Radius of the dot at the end of a stem
+const DOT_RADIUS: f32 = 5.0;
+const PADDING: f32 = 8.0;
+
+#[derive(Props, PartialEq, Clone)]
+pub struct LollipopGraphProps {
+    /// Labels for each axis
+    pub axes: Vec<String>,
+    /// Curves to display, one stem per curve and axis
+    pub curves: Vec<RadarCurve>,
+    /// Curves that are not drawn
+    #[props(default)]
+    pub hidden_curves: Vec<CurveId>,
+    /// Maximum value on the scale
+    pub max_value: f32,
+    /// Width of the SVG element
+    pub width: u32,
+    /// Height of the SVG element
+    pub height: u32,
+    /// Whether values may be edited; curves can additionally opt out
+    #[props(default = true)]
+    pub editable: bool,
+    /// Tooltip state shared with the graph, which draws and edits the tooltip
+    pub tooltip_state: Signal<Option<TooltipData>>,
+    /// Callback for when the pointer enters (`Some`) or leaves (`None`) a value
+    #[props(optional)]
+    pub on_point_hover: Option<EventHandler<Option<PointEvent>>>,
+    /// Callback for when a value is clicked
+    #[props(optional)]
+    pub on_point_click: Option<EventHandler<PointEvent>>,
+    /// Callback for when the pointer enters (`Some`) or leaves (`None`) a stem
+    #[props(optional)]
+    pub on_curve_hover: Option<EventHandler<Option<CurveId>>>,
+    /// Callback for when a stem is clicked
+    #[props(optional)]
+    pub on_curve_click: Option<EventHandler<CurveId>>,
+    /// Drawn on top of the stems, e.g. the tooltip
+    pub children: Element,
+}
+
+/// Horizontal lollipop chart of the same data a radar would show.
+///
+/// A radar needs at least three axes to enclose an area, so for one or two
+/// axes each value becomes a stem with a dot, grouped by axis. The dots
+/// behave like the data points of a radar: hovering shows the tooltip and
+/// clicking pins it and, if allowed, edits the value.
+#[component]
+pub fn LollipopGraph(props: LollipopGraphProps) -> Element {
+    let theme = use_theme();
+    let curves = props
+        .curves
+        .iter()
+        .filter(|curve| !props.hidden_curves.contains(&curve.id))
+        .collect::<Vec<_>>();
+
+    let (width, height) = (props.width as f32, props.height as f32);
+    let labels = props
+        .axes
+        .iter()
+        .map(|axis| AxisLabel::wrap(axis, MAX_LABEL_WIDTH, 1))
+        .collect::<Vec<_>>();
+    let label_width = labels
+        .iter()
+        .map(|label| label.size().0)
+        .fold(0.0, f32::max);
+    let plot_left = PADDING + label_width + PADDING;
+    let plot_width = (width - plot_left - VALUE_WIDTH - PADDING).max(0.0);
+    let band_height = (height - NOTICE_HEIGHT - PADDING) / props.axes.len().max(1) as f32;
+    let row_height = (band_height / (curves.len() + 1) as f32).min(MAX_ROW_HEIGHT);
+
+    let bands = props.axes.iter().enumerate().map(|(axis_index, axis)| {
+        let band_top = NOTICE_HEIGHT + axis_index as f32 * band_height;
+        let band_middle = band_top + band_height / 2.0;
+        let label = &labels[axis_index];
+        // Rows are centered in the band
+        let first_row = band_middle - row_height * (curves.len() as f32 - 1.0) / 2.0;
+
+        let stems = curves.iter().enumerate().filter_map(|(row, curve)| {
+            let data_point = curve.data_points.get(axis_index)?;
+            let value = data_point.value;
+            let end_x = plot_left + plot_width * (value / props.max_value).clamp(0.0, 1.0);
+            let y = first_row + row as f32 * row_height;
+            let color = curve.color.unwrap_or_default();
+            let text = format_value(value);
+            let hover_id = curve.id.clone();
+            let click_id = curve.id.clone();
+            Some(rsx! {
+                g {
+                    key: "{curve.id}",
+                    line {
+                        x1: "{plot_left}",
+                        y1: "{y}",
+                        x2: "{end_x}",
+                        y2: "{y}",
+                        stroke: "{color}",
+                        "stroke-width": "2",
+                    }
+                    // Wide invisible stem that picks up hover and clicks on the curve
+                    line {
+                        class: "cursor-pointer",
+                        x1: "{plot_left}",
+                        y1: "{y}",
+                        x2: "{end_x}",
+                        y2: "{y}",
+                        stroke: "transparent",
+                        "stroke-width": "10",
+                        onmouseenter: move |_| {
+                            if let Some(handler) = &props.on_curve_hover {
+                                handler.call(Some(hover_id.clone()));
+                            }
+                        },
+                        onmouseleave: move |_| {
+                            if let Some(handler) = &props.on_curve_hover {
+                                handler.call(None);
+                            }
+                        },
+                        onclick: move |_| {
+                            if let Some(handler) = &props.on_curve_click {
+                                handler.call(click_id.clone());
+                            }
+                        },
+                    }
+                    text {
+                        x: "{end_x + DOT_RADIUS + 4.0}",
+                        y: "{y}",
+                        "dominant-baseline": "middle",
+                        "font-size": "10",
+                        fill: "{theme.grid_label}",
+                        "{text}"
+                    }
+                    DataPoint {
+                        x: end_x,
+                        y: y,
+                        content: data_point.tooltip_content(curve, axis_index),
+                        color: color,
+                        marker: MarkerShape::Circle,
+                        marker_size: DOT_RADIUS,
+                        editable: props.editable && curve.editable,
+                        tooltip_state: props.tooltip_state,
+                        on_hover: props.on_point_hover,
+                        on_click: props.on_point_click,
+                    }
+                }
+            })
+        });
+
+        rsx! {
+            g {
+                key: "{axis_index}",
+                text {
+                    x: "{plot_left - PADDING}",
+                    y: "{band_middle}",
+                    "text-anchor": "end",
+                    "dominant-baseline": "middle",
+                    "font-size": "12px",
+                    fill: "{theme.axis_label}",
+                    if label.truncated {
+                        title { "{axis}" }
+                    }
+                    "{label.lines[0]}"
+                }
+                {stems}
+            }
+        }
+    });
+
+    rsx! {
+        div {
+            class: "radar-graph-container",
+            svg {
+                class: "radar-lollipop",
+                width: "{props.width}",
+                height: "{props.height}",
+                view_box: "0 0 {props.width} {props.height}",
+
+                text {
+                    x: "{width / 2.0}",
+                    y: "{NOTICE_HEIGHT / 2.0}",
+                    "text-anchor": "middle",
+                    "dominant-baseline": "middle",
+                    "font-size": "11",
+                    "font-style": "italic",
+                    fill: "{theme.grid_label}",
+                    "A radar chart needs at least 3 axes, showing the values as bars instead"
+                }
+
+                // Baseline the stems start from
+                line {
+                    x1: "{plot_left}",
+                    y1: "{NOTICE_HEIGHT}",
+                    x2: "{plot_left}",
+                    y2: "{height - PADDING}",
+                    stroke: "{theme.axis}",
+                    "stroke-width": "1",
+                }
+
+                {bands}
+
+                {props.children}
+            }
+        }
+    }
+}
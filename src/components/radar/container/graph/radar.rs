@@ -1,54 +1,227 @@
+use super::lollipop::LollipopGraph;
+use crate::components::axis_tooltip::{AxisTooltip, AxisTooltipRow};
+use crate::components::color::Color;
 use crate::components::editable_tooltip::EditableTooltip;
+use crate::components::radar::container::RadarError;
+use crate::components::theme::use_theme;
+use crate::components::tooltip::{format_value, TooltipContent};
+use crate::components::utils::{estimate_width, unique_id};
 use dioxus::hooks::use_signal;
 use dioxus::prelude::*;
+use std::fmt;
 
 // Import components
-use self::axis::RadarAxis;
-use self::curve::RadarCurveVisual;
+use self::axis::{AxisLabel, AxisLabelMode, RadarAxis, LINE_HEIGHT};
+use self::curve::{CurveEmphasis, RadarCurveVisual};
+use self::events::{AxisEvent, PointEvent, PointSelection, TooltipPinEvent, ValueChange};
+use self::geometry::{Direction, RadarGeometry};
 use self::grid::RadarGrid;
+use self::labels::{value_label_points, LabelPlacer, ValueLabels};
+use self::patterns::{pattern_id, PatternDefs};
+use self::sectors::{sectors, RadarSectors, RING_WIDTH};
+use self::style::CurveStyle;
 
 // Component modules
 pub mod axis;
 pub mod curve;
+pub mod events;
+pub mod geometry;
 pub mod grid;
+pub mod labels;
+pub mod patterns;
+pub mod sectors;
+pub mod style;
 
 /// Data structure for tooltip information
 #[derive(Clone, PartialEq)]
 pub struct TooltipData {
-    /// Index of the curve
-    pub curve_index: usize,
-    /// Index of the data point within the curve
-    pub point_index: usize,
-    /// Label to display in the tooltip
-    pub label: String,
-    /// Current value of the data point
-    pub value: f32,
+    /// What the tooltip shows, including the data point it belongs to
+    pub content: TooltipContent,
     /// X-coordinate of the tooltip
     pub x: f32,
     /// Y-coordinate of the tooltip
     pub y: f32,
     /// Color of the curve
-    pub color: String,
+    pub color: Color,
     /// Whether the tooltip is pinned (remains visible when not hovering)
     pub pinned: bool,
     /// Whether the tooltip is currently being edited
     pub editing: bool,
+    /// Whether the value may be edited at all
+    pub editable: bool,
+}
+
+impl TooltipData {
+    /// Returns the event describing the data point of this tooltip
+    pub fn point_event(&self) -> PointEvent {
+        PointEvent {
+            curve_id: self.content.curve_id.clone(),
+            point_index: self.content.point_index,
+            axis: self.content.axis.clone(),
+            value: self.content.value,
+        }
+    }
 }
 
 /// A data point for the radar graph.
 /// Each data point represents a value for a specific axis.
-#[derive(Clone, PartialEq)]
+#[derive(Clone, Default, PartialEq)]
 pub struct DataPoint {
     pub value: f32,
     pub label: String,
+    /// Optional remark shown in the tooltip below the value
+    pub note: Option<String>,
+}
+
+impl DataPoint {
+    /// Create a data point for the axis `label`
+    pub fn new(label: impl Into<String>, value: f32) -> Self {
+        Self {
+            value,
+            label: label.into(),
+            note: None,
+        }
+    }
+
+    /// Attach a remark that is shown in the tooltip
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.note = Some(note.into());
+        self
+    }
+
+    /// Tooltip content of this point on the given curve
+    pub fn tooltip_content(&self, curve: &RadarCurve, point_index: usize) -> TooltipContent {
+        TooltipContent {
+            curve_id: curve.id.clone(),
+            curve_name: curve.name.clone(),
+            point_index,
+            axis: self.label.clone(),
+            value: self.value,
+            formatted_value: format_value(self.value),
+            notes: self.note.iter().cloned().collect(),
+        }
+    }
+}
+
+/// Stable identifier of a curve.
+///
+/// Unlike the curve's position or display name, the id stays the same when
+/// curves are renamed, reordered or removed, so UI state keyed by it survives.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CurveId(pub String);
+
+impl CurveId {
+    /// Returns the id as a string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for CurveId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for CurveId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+
+impl From<String> for CurveId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
 }
 
 /// Data for a single curve in the radar graph.
 #[derive(Clone, PartialEq)]
 pub struct RadarCurve {
+    /// Stable identifier used to key visibility, tooltip and edit state
+    pub id: CurveId,
     pub name: String,
     pub data_points: Vec<DataPoint>,
-    pub color: String,
+    /// Color of the curve; `None` lets the container pick one from its palette
+    pub color: Option<Color>,
+    /// Whether the values of this curve may be edited (e.g. `false` for baselines)
+    pub editable: bool,
+    /// Stroke, fill and marker style
+    pub style: CurveStyle,
+    /// Optional group the curve is listed under in the legend
+    pub group: Option<String>,
+}
+
+impl Default for RadarCurve {
+    fn default() -> Self {
+        Self {
+            id: CurveId::default(),
+            name: String::new(),
+            data_points: Vec::new(),
+            color: None,
+            editable: true,
+            style: CurveStyle::default(),
+            group: None,
+        }
+    }
+}
+
+impl RadarCurve {
+    /// Create a curve whose id is derived from its name.
+    ///
+    /// This keeps callers that never cared about ids working; use
+    /// [`RadarCurve::with_id`] when the name may change later. The curve has
+    /// no color, so it gets one from the palette unless one is set.
+    pub fn new(name: impl Into<String>, data_points: Vec<DataPoint>) -> Self {
+        let name = name.into();
+        Self {
+            id: CurveId(name.clone()),
+            name,
+            data_points,
+            ..Default::default()
+        }
+    }
+
+    /// Set the color of the curve
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Set the color of the curve from a CSS color string
+    pub fn with_color_str(self, color: &str) -> Result<Self, RadarError> {
+        match Color::parse(color) {
+            Ok(color) => Ok(self.with_color(color)),
+            Err(source) => Err(RadarError::InvalidColor {
+                curve_name: self.name,
+                source,
+            }),
+        }
+    }
+
+    /// Replace the id of the curve
+    pub fn with_id(mut self, id: impl Into<CurveId>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Replace the style of the curve
+    pub fn with_style(mut self, style: CurveStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Put the curve into a legend group
+    pub fn with_group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
+    /// Allow or forbid editing the values of the curve
+    pub fn with_editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
 }
 
 /// Props for the RadarGraph component.
@@ -58,6 +231,9 @@ pub struct RadarGraphProps {
     pub axes: Vec<String>,
     /// List of curves to be displayed
     pub curves: Vec<RadarCurve>,
+    /// Curves that are rendered invisible, so they can fade in and out
+    #[props(default)]
+    pub hidden_curves: Vec<CurveId>,
     /// Maximum value for all axes (scale)
     #[props(default = 100.0)]
     pub max_value: f32,
@@ -67,9 +243,85 @@ pub struct RadarGraphProps {
     /// Height of the SVG element
     #[props(default = 500)]
     pub height: u32,
+    /// Whether values may be edited; when false clicking a point only pins
+    /// a read-only tooltip. Curves can additionally opt out individually.
+    #[props(default = true)]
+    pub editable: bool,
+    /// Smoothly animate value changes and visibility toggles. Users asking
+    /// for reduced motion never see animations.
+    #[props(default = true)]
+    pub animate: bool,
+    /// Let the curves grow from the center when they first appear
+    #[props(default = false)]
+    pub intro_animation: bool,
+    /// Angle of the first axis in degrees, clockwise from 12 o'clock
+    #[props(default = 0.0)]
+    pub start_angle: f32,
+    /// Direction in which the axes follow each other
+    #[props(default)]
+    pub direction: Direction,
+    /// Angle in degrees the axes are spread over, e.g. 180 for a semicircle
+    #[props(default = 360.0)]
+    pub sweep: f32,
+    /// Maximum width of an axis label line; longer labels wrap
+    #[props(default = 100.0)]
+    pub axis_label_width: f32,
+    /// Maximum number of lines per axis label, the rest is cut off
+    #[props(default = 2)]
+    pub axis_label_lines: usize,
+    /// Whether axis labels are upright, rotated or thinned out
+    #[props(default)]
+    pub axis_label_mode: AxisLabelMode,
+    /// Optional group of each axis; neighboring axes of a group form a
+    /// shaded sector with the group name in a ring around the plot
+    #[props(default)]
+    pub axis_groups: Vec<Option<String>>,
+    /// Write each curve's name next to its outermost vertex
+    #[props(default = false)]
+    pub direct_labels: bool,
+    /// Write the values next to the data points, e.g. for printed reports
+    #[props(default)]
+    pub value_labels: ValueLabels,
+    /// Content drawn on top of the chart in the top right corner, e.g. a legend
+    #[props(optional)]
+    pub overlay: Option<Element>,
+    /// Curve that is temporarily highlighted, e.g. while hovering its legend entry
+    #[props(optional)]
+    pub highlighted_curve: Option<CurveId>,
+    /// Curve that stays highlighted until it is clicked again
+    #[props(optional)]
+    pub focused_curve: Option<CurveId>,
+    /// Custom renderer for the tooltip content; editing still uses the
+    /// built-in input
+    #[props(optional)]
+    pub render_tooltip: Option<Callback<TooltipContent, Element>>,
+    /// Width and height of tooltips drawn by `render_tooltip`
+    #[props(default = (180.0, 60.0))]
+    pub tooltip_size: (f32, f32),
     /// Callback for when a data point value is updated
     #[props(optional)]
-    pub on_value_change: Option<EventHandler<(usize, usize, f32)>>,
+    pub on_value_change: Option<EventHandler<ValueChange>>,
+    /// Callback for when the pinned data point changes (`None` when unpinned)
+    #[props(optional)]
+    pub on_selection_change: Option<EventHandler<Option<PointSelection>>>,
+    /// Callback for when the pointer enters (`Some`) or leaves (`None`) a data point
+    #[props(optional)]
+    pub on_point_hover: Option<EventHandler<Option<PointEvent>>>,
+    /// Callback for when a data point is clicked
+    #[props(optional)]
+    pub on_point_click: Option<EventHandler<PointEvent>>,
+    /// Callback for when an axis label is clicked
+    #[props(optional)]
+    pub on_axis_label_click: Option<EventHandler<AxisEvent>>,
+    /// Callback for when a tooltip gets pinned or unpinned
+    #[props(optional)]
+    pub on_tooltip_pin_change: Option<EventHandler<TooltipPinEvent>>,
+    /// Callback for when the pointer enters (`Some`) or leaves (`None`) a curve
+    #[props(optional)]
+    pub on_curve_hover: Option<EventHandler<Option<CurveId>>>,
+    /// Callback for when the outline of a curve is clicked
+    #[props(optional)]
+    pub on_curve_click: Option<EventHandler<CurveId>>,
 }
 
 // Implement PartialEq manually since EventHandler doesn't implement PartialEq
@@ -77,10 +329,27 @@ impl PartialEq for RadarGraphProps {
     fn eq(&self, other: &Self) -> bool {
         self.axes == other.axes
             && self.curves == other.curves
+            && self.hidden_curves == other.hidden_curves
             && self.max_value == other.max_value
             && self.width == other.width
             && self.height == other.height
-        // Note: We can't compare EventHandler, so we ignore on_value_change in equality
+            && self.editable == other.editable
+            && self.animate == other.animate
+            && self.intro_animation == other.intro_animation
+            && self.start_angle == other.start_angle
+            && self.direction == other.direction
+            && self.sweep == other.sweep
+            && self.axis_label_width == other.axis_label_width
+            && self.axis_label_lines == other.axis_label_lines
+            && self.axis_label_mode == other.axis_label_mode
+            && self.axis_groups == other.axis_groups
+            && self.direct_labels == other.direct_labels
+            && self.value_labels == other.value_labels
+            && self.tooltip_size == other.tooltip_size
+            && self.overlay == other.overlay
+            && self.highlighted_curve == other.highlighted_curve
+            && self.focused_curve == other.focused_curve
+        // Note: We can't compare EventHandler, so we ignore the callbacks in equality
     }
 }
 
@@ -103,26 +372,46 @@ pub fn RadarGraph(props: RadarGraphProps) -> Element {
         };
     }
 
-    let center_x = props.width as f32 / 2.0;
-    let center_y = props.height as f32 / 2.0;
-    let radius = f32::min(center_x, center_y) * 0.8;
+    // Prefix for the ids of the pattern fills of this graph
+    let pattern_prefix = use_hook(|| unique_id("radar-pattern"));
 
     // Create a shared signal for tooltip state that all curves can access
     let mut tooltip_state = use_signal(|| None::<TooltipData>);
 
-    // Generate curves for each data set
-    let curve_components = props.curves.iter().enumerate().map(|(curve_idx, curve)| {
-        rsx! {
-            RadarCurveVisual {
-                curve: curve.clone(),
-                curve_index: curve_idx,
-                axes: props.axes.clone(),
-                center_x: center_x,
-                center_y: center_y,
-                radius: radius,
-                max_value: props.max_value,
-                tooltip_state: tooltip_state,
+    // Report changes of the pinned data point to the parent
+    let on_selection_change = props.on_selection_change;
+    let on_tooltip_pin_change = props.on_tooltip_pin_change;
+    let mut last_pinned = use_signal(|| None::<PointEvent>);
+    use_effect(move || {
+        let pinned = tooltip_state
+            .read()
+            .as_ref()
+            .filter(|data| data.pinned)
+            .map(TooltipData::point_event);
+
+        let previous = last_pinned.peek().clone();
+        let selection = pinned.as_ref().map(PointEvent::selection);
+        if previous.as_ref().map(PointEvent::selection) == selection {
+            return;
+        }
+        last_pinned.set(pinned.clone());
+
+        if let Some(handler) = &on_tooltip_pin_change {
+            if let Some(point) = previous {
+                handler.call(TooltipPinEvent {
+                    point,
+                    pinned: false,
+                });
             }
+            if let Some(point) = pinned {
+                handler.call(TooltipPinEvent {
+                    point,
+                    pinned: true,
+                });
+            }
+        }
+        if let Some(handler) = &on_selection_change {
+            handler.call(selection);
         }
     });
 
@@ -131,7 +420,7 @@ pub fn RadarGraph(props: RadarGraphProps) -> Element {
 
     let handle_start_edit = move |_| {
         let current = tooltip_state_for_handlers.read().clone();
-        if let Some(mut data) = current {
+        if let Some(mut data) = current.filter(|data| data.editable) {
             data.editing = true;
             tooltip_state_for_handlers.set(Some(data));
         }
@@ -139,24 +428,31 @@ pub fn RadarGraph(props: RadarGraphProps) -> Element {
 
     let handle_complete_edit = {
         let on_value_change = props.on_value_change;
+        let curve_ids = props
+            .curves
+            .iter()
+            .map(|curve| curve.id.clone())
+            .collect::<Vec<_>>();
         let mut tooltip_state_for_complete = tooltip_state;
 
         move |new_value: f32| {
             let current = tooltip_state_for_complete.read().clone();
             if let Some(mut data) = current {
-                // Call the value change callback if provided
-                if let Some(callback) = &on_value_change {
-                    callback.call((data.curve_index, data.point_index, new_value));
+                // Call the value change callback if provided, unless the
+                // curve was removed while it was being edited
+                let curve_index = curve_ids.iter().position(|id| id == &data.content.curve_id);
+                if let (Some(callback), Some(curve_index)) = (&on_value_change, curve_index) {
+                    callback.call(ValueChange {
+                        curve_id: data.content.curve_id.clone(),
+                        curve_index,
+                        point_index: data.content.point_index,
+                        value: new_value,
+                    });
                 }
 
                 // Update the tooltip data and stop editing
-                data.value = new_value;
+                data.content.set_value(new_value);
                 data.editing = false;
-                data.label = format!(
-                    "{}: {}",
-                    data.label.split(':').next().unwrap_or(""),
-                    new_value
-                );
                 tooltip_state_for_complete.set(Some(data));
             }
         }
@@ -179,14 +475,16 @@ pub fn RadarGraph(props: RadarGraphProps) -> Element {
                 EditableTooltip {
                     x: data.x,
                     y: data.y,
-                    content: data.label.clone(),
-                    value: data.value,
-                    curve_index: data.curve_index,
-                    point_index: data.point_index,
+                    content: data.content.clone(),
                     visible: true,
                     pinned: data.pinned,
                     editing: data.editing,
-                    color: data.color.clone(),
+                    editable: data.editable,
+                    // Lighter accent stays visible on the dark tooltip background
+                    color: data.color.lighten(0.2).to_string(),
+                    bounds: (props.width as f32, props.height as f32),
+                    render: props.render_tooltip,
+                    custom_size: props.tooltip_size,
                     on_start_edit: handle_start_edit,
                     on_complete_edit: handle_complete_edit,
                     on_cancel_edit: handle_cancel_edit,
@@ -197,33 +495,337 @@ pub fn RadarGraph(props: RadarGraphProps) -> Element {
         }
     };
 
+    // One or two axes don't enclose an area, show the values as bars instead
+    if axes_count < 3 {
+        return rsx! {
+            LollipopGraph {
+                axes: props.axes.clone(),
+                curves: props.curves.clone(),
+                hidden_curves: props.hidden_curves.clone(),
+                max_value: props.max_value,
+                width: props.width,
+                height: props.height,
+                editable: props.editable,
+                tooltip_state: tooltip_state,
+                on_point_hover: props.on_point_hover,
+                on_point_click: props.on_point_click,
+                on_curve_hover: props.on_curve_hover,
+                on_curve_click: props.on_curve_click,
+                {tooltip}
+            }
+        };
+    }
+
+    let theme = use_theme();
+    // Leave just enough room around the plot for the axis labels
+    let wrap_labels = |lines: usize| {
+        props
+            .axes
+            .iter()
+            .map(|axis| AxisLabel::wrap(axis, props.axis_label_width, lines))
+            .collect::<Vec<_>>()
+    };
+    let axis_sectors = sectors(&props.axis_groups, axes_count);
+    let ring = if axis_sectors.is_empty() {
+        0.0
+    } else {
+        RING_WIDTH
+    };
+    let layout = RadarGeometry::new(
+        (0.0, 0.0),
+        1.0,
+        axes_count,
+        props.start_angle,
+        props.direction,
+        props.sweep,
+    )
+    .with_ring(ring);
+    let fit = |labels: &[AxisLabel], rotated: bool| {
+        layout.fit(labels, rotated, props.width as f32, props.height as f32)
+    };
+    let mut label_mode = props.axis_label_mode.resolve(axes_count);
+    let rotated = label_mode == AxisLabelMode::Rotated;
+    let mut axis_labels = wrap_labels(if rotated { 1 } else { props.axis_label_lines });
+    let mut geometry = fit(&axis_labels, rotated);
+    // Too many axes even for rotated labels, leave out the ones that overlap
+    if props.axis_label_mode == AxisLabelMode::Auto
+        && rotated
+        && geometry.label_spacing() < LINE_HEIGHT
+    {
+        label_mode = AxisLabelMode::Thinned;
+        axis_labels = wrap_labels(1);
+        geometry = fit(&axis_labels, false);
+    }
+
+    // Hovering takes precedence over the persistent focus
+    let emphasized = props
+        .highlighted_curve
+        .as_ref()
+        .or(props.focused_curve.as_ref())
+        .filter(|id| !props.hidden_curves.contains(id));
+
+    // SVG has no z-index, so the emphasized curve is moved to the end to be
+    // painted on top; the keys let the curves keep their state while moving
+    let mut layered = props.curves.iter().enumerate().collect::<Vec<_>>();
+    layered.sort_by_key(|(_, curve)| Some(&curve.id) == emphasized);
+
+    // Generate curves for each data set
+    let curve_components = layered.into_iter().map(|(index, curve)| {
+        let fill_pattern_id = curve
+            .style
+            .pattern
+            .map(|_| pattern_id(&pattern_prefix, index));
+
+        rsx! {
+            RadarCurveVisual {
+                key: "{curve.id}",
+                curve: curve.clone(),
+                pattern_id: fill_pattern_id,
+                visible: !props.hidden_curves.contains(&curve.id),
+                axes: props.axes.clone(),
+                geometry: geometry,
+                max_value: props.max_value,
+                editable: props.editable && curve.editable,
+                emphasis: CurveEmphasis::of(&curve.id, emphasized),
+                tooltip_state: tooltip_state,
+                on_point_hover: props.on_point_hover,
+                on_point_click: props.on_point_click,
+                on_hover: props.on_curve_hover,
+                on_click: props.on_curve_click,
+            }
+        }
+    });
+
+    // Hovering an axis compares every visible curve on it
+    let mut hovered_axis = use_signal(|| None::<usize>);
+    // Whether the grow-from-center intro has finished
+    let mut intro_played = use_signal(|| false);
+    let axis_crosshair = hovered_axis()
+        .filter(|&index| index < axes_count)
+        .map(|index| {
+            let visible_curves = props
+                .curves
+                .iter()
+                .filter(|curve| !props.hidden_curves.contains(&curve.id))
+                .filter_map(|curve| Some((curve, curve.data_points.get(index)?.value)))
+                .collect::<Vec<_>>();
+
+            // Rings on the vertices of all curves on the hovered axis
+            let rings = visible_curves.iter().map(|(curve, value)| {
+                let (x, y) = geometry.point(index, (value / props.max_value).clamp(0.0, 1.0));
+                let color = curve.color.unwrap_or_default();
+                rsx! {
+                    circle {
+                        key: "{curve.id}",
+                        cx: "{x}",
+                        cy: "{y}",
+                        r: "6",
+                        fill: "none",
+                        stroke: "{color}",
+                        "stroke-width": "2",
+                    }
+                }
+            });
+
+            let mut rows = visible_curves
+                .iter()
+                .map(|(curve, value)| AxisTooltipRow {
+                    name: curve.name.clone(),
+                    value: *value,
+                    color: curve.color.unwrap_or_default(),
+                })
+                .collect::<Vec<_>>();
+            rows.sort_by(|a, b| b.value.total_cmp(&a.value));
+            let (x, y) = geometry.point(index, 1.0);
+
+            rsx! {
+                g {
+                    class: "radar-axis-crosshair",
+                    style: "pointer-events: none;",
+                    {rings}
+                }
+                AxisTooltip {
+                    x: x,
+                    y: y,
+                    bounds_width: props.width as f32,
+                    bounds_height: props.height as f32,
+                    title: props.axes[index].clone(),
+                    rows: rows,
+                }
+            }
+        });
+
+    // Direct labels and value labels share one placer so they avoid each other
+    let mut placer = LabelPlacer::new(props.width as f32, props.height as f32);
+    let labeled_curves = props
+        .curves
+        .iter()
+        .filter(|curve| !props.hidden_curves.contains(&curve.id))
+        .filter(|curve| curve.data_points.len() == axes_count)
+        .collect::<Vec<_>>();
+
+    // Curve names next to their outermost vertex, placed so they don't overlap
+    let direct_labels = props.direct_labels.then(|| {
+        labeled_curves
+            .iter()
+            .map(|curve| {
+                let ratio = |value: f32| (value / props.max_value).clamp(0.0, 1.0);
+                let (index, outermost) = curve
+                    .data_points
+                    .iter()
+                    .enumerate()
+                    .max_by(|(_, a), (_, b)| ratio(a.value).total_cmp(&ratio(b.value)))
+                    .map(|(index, point)| (index, ratio(point.value)))
+                    .unwrap_or_default();
+                let angle = geometry.angle(index);
+                let (x, y) = geometry.point(index, outermost);
+                let label = placer.place(x, y, angle, estimate_width(&curve.name, 12.0), 14.0);
+                let color = curve.color.unwrap_or_default();
+
+                rsx! {
+                    text {
+                        key: "{curve.id}",
+                        x: "{label.x}",
+                        y: "{label.y}",
+                        "text-anchor": "{label.anchor}",
+                        "dominant-baseline": "middle",
+                        "font-size": "12",
+                        "font-weight": "600",
+                        fill: "{color}",
+                        // Halo in the background color keeps the text readable over curves
+                        stroke: "{theme.background}",
+                        "stroke-width": "3",
+                        "paint-order": "stroke",
+                        "{curve.name}"
+                    }
+                }
+            })
+            .collect::<Vec<_>>()
+    });
+
+    // Formatted values next to the data points, pushed outward along the axis
+    let value_labels = value_label_points(
+        &labeled_curves,
+        props.value_labels,
+        props.focused_curve.as_ref(),
+    )
+    .into_iter()
+    .map(|(curve_index, point_index)| {
+        let curve = labeled_curves[curve_index];
+        let value = curve.data_points[point_index].value;
+        let angle = geometry.angle(point_index);
+        let (x, y) = geometry.point(point_index, (value / props.max_value).clamp(0.0, 1.0));
+        let text = format_value(value);
+        let label = placer.place(x, y, angle, estimate_width(&text, 10.0), 12.0);
+        let color = curve.color.unwrap_or_default();
+
+        rsx! {
+            text {
+                key: "{curve.id}-{point_index}",
+                x: "{label.x}",
+                y: "{label.y}",
+                "text-anchor": "{label.anchor}",
+                "dominant-baseline": "middle",
+                "font-size": "10",
+                fill: "{color.darken(0.2)}",
+                stroke: "{theme.background}",
+                "stroke-width": "3",
+                "paint-order": "stroke",
+                "{text}"
+            }
+        }
+    })
+    .collect::<Vec<_>>();
+
+    // Animations are driven by CSS classes, see `tailwind.css`. The intro
+    // class is dropped once it played, so curves that are re-inserted while
+    // moving to the top don't grow again
+    let animation_class = match (props.animate, props.intro_animation && !intro_played()) {
+        (false, _) => "",
+        (true, false) => "radar-animated",
+        (true, true) => "radar-animated radar-intro",
+    };
+
     rsx! {
         div {
             class: "radar-graph-container",
             svg {
+                class: "{animation_class}",
+                onanimationend: move |_| intro_played.set(true),
                 width: "{props.width}",
                 height: "{props.height}",
                 view_box: "0 0 {props.width} {props.height}",
 
+                // Pattern fills referenced by the curves
+                PatternDefs {
+                    prefix: pattern_prefix.clone(),
+                    curves: props.curves.clone(),
+                }
+
                 // Grid circles
                 RadarGrid {
-                    center_x: center_x,
-                    center_y: center_y,
-                    radius: radius,
+                    geometry: geometry,
                     max_value: props.max_value,
+                    sectors: axis_sectors.clone(),
+                }
+
+                // Sector separators and the ring of group names
+                if !axis_sectors.is_empty() {
+                    RadarSectors {
+                        sectors: axis_sectors,
+                        axes_count: axes_count,
+                        geometry: geometry,
+                    }
                 }
 
                 // Axis lines and labels
                 RadarAxis {
                     labels: props.axes.clone(),
-                    center_x: center_x,
-                    center_y: center_y,
-                    radius: radius,
+                    wrapped: axis_labels,
+                    mode: label_mode,
+                    geometry: geometry,
+                    highlighted: hovered_axis(),
+                    on_label_click: props.on_axis_label_click,
+                    on_hover: move |axis| hovered_axis.set(axis),
                 }
 
                 // Curve polygons
                 {curve_components}
 
+                if let Some(labels) = direct_labels {
+                    g {
+                        class: "radar-direct-labels",
+                        style: "pointer-events: none;",
+                        {labels.into_iter()}
+                    }
+                }
+
+                if !value_labels.is_empty() {
+                    g {
+                        class: "radar-value-labels",
+                        style: "pointer-events: none;",
+                        {value_labels.into_iter()}
+                    }
+                }
+
+                // Overlay content, e.g. a legend placed inside the chart
+                if let Some(overlay) = props.overlay.clone() {
+                    foreignObject {
+                        x: "{props.width as f32 - 180.0}",
+                        y: "0",
+                        width: "180",
+                        height: "{props.height}",
+                        // Only the content itself should catch the pointer
+                        style: "pointer-events: none;",
+                        div {
+                            style: "pointer-events: auto; width: fit-content; margin-left: auto;",
+                            {overlay}
+                        }
+                    }
+                }
+
+                {axis_crosshair}
+
                 // Tooltip at the very top layer
                 {tooltip}
             }
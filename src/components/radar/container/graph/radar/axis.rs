@@ -1,73 +1,267 @@
-use crate::components::utils::polar_to_cartesian;
+use super::events::AxisEvent;
+use super::geometry::RadarGeometry;
+use super::labels::LabelBox;
+use crate::components::theme::use_theme;
+use crate::components::utils::estimate_width;
 use dioxus::prelude::*;
-use std::f32::consts::PI;
+
+/// Font size of the axis labels
+const LABEL_FONT_SIZE: f32 = 12.0;
+/// Distance between two lines of a wrapped label
+pub const LINE_HEIGHT: f32 = LABEL_FONT_SIZE * 1.2;
+/// Most axes that [`AxisLabelMode::Auto`] still labels horizontally
+const HORIZONTAL_LIMIT: usize = 16;
+
+/// How the axis labels are drawn
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum AxisLabelMode {
+    /// Horizontal for a few axes, rotated for many, and thinned out when
+    /// even rotated labels would overlap
+    #[default]
+    Auto,
+    /// Upright labels that may wrap onto several lines
+    Horizontal,
+    /// Single-line labels running outward along their axis
+    Rotated,
+    /// Horizontal labels, leaving out every label that would overlap one
+    /// already drawn; hidden labels appear while their axis is hovered
+    Thinned,
+}
+
+impl AxisLabelMode {
+    /// The mode to lay out `axes_count` axes with; `Auto` becomes horizontal
+    /// or rotated and may still fall back to thinned once the radius is known
+    pub fn resolve(self, axes_count: usize) -> Self {
+        match self {
+            Self::Auto if axes_count <= HORIZONTAL_LIMIT => Self::Horizontal,
+            Self::Auto => Self::Rotated,
+            mode => mode,
+        }
+    }
+}
+
+/// Which labels a thinned axis shows: each label is kept unless it overlaps
+/// one kept before it
+pub fn thinned(geometry: &RadarGeometry, labels: &[AxisLabel]) -> Vec<bool> {
+    let mut kept: Vec<LabelBox> = Vec::new();
+    labels
+        .iter()
+        .enumerate()
+        .map(|(index, label)| {
+            let (left, top, right, bottom) = geometry.label_bounds(index, label.size(), false);
+            let label_box = LabelBox {
+                x: left,
+                y: top,
+                width: right - left,
+                height: bottom - top,
+            };
+            let free = kept.iter().all(|other| !other.overlaps(&label_box));
+            if free {
+                kept.push(label_box);
+            }
+            free
+        })
+        .collect()
+}
+
+/// An axis label broken into lines that fit the maximum label width
+#[derive(Clone, Debug, PartialEq)]
+pub struct AxisLabel {
+    pub lines: Vec<String>,
+    /// Whether text was cut off, so the full text should be shown on hover
+    pub truncated: bool,
+}
+
+impl AxisLabel {
+    /// Wrap `text` at word boundaries into at most `max_lines` lines of at
+    /// most `max_width`, ending with an ellipsis where text had to be cut
+    pub fn wrap(text: &str, max_width: f32, max_lines: usize) -> Self {
+        let fits = |line: &str| estimate_width(line, LABEL_FONT_SIZE) <= max_width;
+        let mut lines: Vec<String> = Vec::new();
+        let mut current = String::new();
+        for word in text.split_whitespace() {
+            let candidate = if current.is_empty() {
+                word.to_string()
+            } else {
+                format!("{current} {word}")
+            };
+            if current.is_empty() || fits(&candidate) {
+                current = candidate;
+            } else {
+                lines.push(std::mem::replace(&mut current, word.to_string()));
+            }
+        }
+        lines.push(current);
+
+        let max_lines = max_lines.max(1);
+        let mut truncated = false;
+        if lines.len() > max_lines {
+            let rest = lines.split_off(max_lines - 1).join(" ");
+            lines.push(rest);
+            truncated = true;
+        }
+        for line in lines.iter_mut().filter(|line| !fits(line)) {
+            while !line.is_empty() && !fits(&format!("{line}…")) {
+                line.pop();
+            }
+            *line = format!("{}…", line.trim_end());
+            truncated = true;
+        }
+
+        Self { lines, truncated }
+    }
+
+    /// Estimated width and height of the label
+    pub fn size(&self) -> (f32, f32) {
+        let width = self
+            .lines
+            .iter()
+            .map(|line| estimate_width(line, LABEL_FONT_SIZE))
+            .fold(0.0, f32::max);
+        (width, self.lines.len() as f32 * LINE_HEIGHT)
+    }
+}
 
 #[derive(Props, PartialEq, Clone)]
 pub struct RadarAxisProps {
     /// Labels for each axis
     pub labels: Vec<String>,
-    /// Center X coordinate
-    pub center_x: f32,
-    /// Center Y coordinate
-    pub center_y: f32,
-    /// Radius of the graph
-    pub radius: f32,
+    /// The labels broken into the lines that are drawn
+    pub wrapped: Vec<AxisLabel>,
+    /// How the labels are drawn, already resolved from `Auto`
+    #[props(default)]
+    pub mode: AxisLabelMode,
+    /// Position and angles of the axes
+    pub geometry: RadarGeometry,
+    /// Index of the axis drawn highlighted, e.g. while it is hovered
+    #[props(optional)]
+    pub highlighted: Option<usize>,
+    /// Callback for when an axis label is clicked
+    #[props(optional)]
+    pub on_label_click: Option<EventHandler<AxisEvent>>,
+    /// Callback for when the pointer enters (`Some`) or leaves (`None`) an
+    /// axis line or label, receives the axis index
+    #[props(optional)]
+    pub on_hover: Option<EventHandler<Option<usize>>>,
 }
 
 /// Component for rendering the axes of a radar graph
 #[component]
 pub fn RadarAxis(props: RadarAxisProps) -> Element {
-    let axes_count = props.labels.len();
-    let axis_angle_step = 2.0 * PI / axes_count as f32;
+    let theme = use_theme();
+    let axes_count = props.labels.len().min(props.wrapped.len());
+    let geometry = props.geometry;
+    let shown = match props.mode {
+        AxisLabelMode::Thinned => thinned(&geometry, &props.wrapped),
+        _ => vec![true; axes_count],
+    };
 
     // Generate axis lines and labels
     let axis_lines = (0..axes_count).map(|i| {
-        let angle = -PI / 2.0 + i as f32 * axis_angle_step;
-        let (end_x, end_y) =
-            polar_to_cartesian(props.radius, angle, props.center_x, props.center_y);
-
-        // Calculate label position (slightly beyond the end of the axis)
-        let label_radius = props.radius * 1.1;
-        let (label_x, label_y) =
-            polar_to_cartesian(label_radius, angle, props.center_x, props.center_y);
-
-        // Text anchor based on position
-        let text_anchor = if label_x < props.center_x - 5.0 {
-            "end"
-        } else if label_x > props.center_x + 5.0 {
-            "start"
+        let (center_x, center_y) = (geometry.center_x, geometry.center_y);
+        let (end_x, end_y) = geometry.point(i, 1.0);
+        let label = &props.wrapped[i];
+        let label_visible = shown[i] || props.highlighted == Some(i);
+
+        // Upright labels are placed as a block beyond the end of the axis,
+        // rotated ones start there and are turned to read left to right
+        let (text_x, first_line_y, anchor, transform) = if props.mode == AxisLabelMode::Rotated {
+            let angle = geometry.angle(i);
+            let (x, y) = geometry.label_anchor(i);
+            let degrees = angle.to_degrees();
+            if angle.cos() < -0.001 {
+                (x, y, "end", format!("rotate({} {x} {y})", degrees + 180.0))
+            } else {
+                (x, y, "start", format!("rotate({degrees} {x} {y})"))
+            }
         } else {
-            "middle"
+            let position = geometry.label_position(i, label.size());
+            (
+                position.text_x,
+                position.top + LINE_HEIGHT / 2.0,
+                position.anchor,
+                String::new(),
+            )
         };
 
-        // Text vertical alignment based on position
-        let dy = if label_y < props.center_y - 5.0 {
-            "-0.5em"
-        } else if label_y > props.center_y + 5.0 {
-            "1em"
+        // Labels are only interactive when someone listens for clicks
+        let on_label_click = props.on_label_click;
+        let label_class = if on_label_click.is_some() {
+            "cursor-pointer"
         } else {
-            "0.3em"
+            ""
         };
+        let on_hover = props.on_hover;
+        let handle_enter = move |_| {
+            if let Some(handler) = &on_hover {
+                handler.call(Some(i));
+            }
+        };
+        let handle_leave = move |_| {
+            if let Some(handler) = &on_hover {
+                handler.call(None);
+            }
+        };
+        let (stroke, stroke_width, font_weight) = if props.highlighted == Some(i) {
+            (theme.axis_label, 2, "600")
+        } else {
+            (theme.axis, 1, "normal")
+        };
+        let axis = props.labels[i].clone();
+        let handle_label_click = move |_| {
+            if let Some(handler) = &on_label_click {
+                handler.call(AxisEvent {
+                    axis_index: i,
+                    axis: axis.clone(),
+                });
+            }
+        };
+
+        let lines = label.lines.iter().enumerate().map(|(line_index, line)| {
+            let line_y = first_line_y + line_index as f32 * LINE_HEIGHT;
+            rsx! {
+                tspan { x: "{text_x}", y: "{line_y}", "{line}" }
+            }
+        });
 
         rsx! {
             line {
-                x1: "{props.center_x}",
-                y1: "{props.center_y}",
+                x1: "{center_x}",
+                y1: "{center_y}",
                 x2: "{end_x}",
                 y2: "{end_y}",
-                stroke: "#888888",
-                class: "dark:stroke-gray-500",
-                "stroke-width": "1"
+                stroke: "{stroke}",
+                "stroke-width": "{stroke_width}"
             }
-            text {
-                x: "{label_x}",
-                y: "{label_y}",
-                "text-anchor": "{text_anchor}",
-                dy: "{dy}",
-                "font-size": "12px",
-                fill: "#333333",
-                class: "dark:fill-gray-200",
-                "{props.labels[i]}"
+            // Wider invisible line that is easier to hover than the axis itself
+            line {
+                x1: "{center_x}",
+                y1: "{center_y}",
+                x2: "{end_x}",
+                y2: "{end_y}",
+                stroke: "transparent",
+                "stroke-width": "10",
+                onmouseenter: handle_enter,
+                onmouseleave: handle_leave,
+            }
+            if label_visible {
+                text {
+                    "text-anchor": "{anchor}",
+                    "dominant-baseline": "middle",
+                    "font-size": "{LABEL_FONT_SIZE}px",
+                    "font-weight": "{font_weight}",
+                    fill: "{theme.axis_label}",
+                    class: "{label_class}",
+                    transform: "{transform}",
+                    onclick: handle_label_click,
+                    onmouseenter: handle_enter,
+                    onmouseleave: handle_leave,
+                    // Native tooltip with the full text of shortened labels
+                    if label.truncated {
+                        title { "{props.labels[i]}" }
+                    }
+                    {lines}
+                }
             }
         }
     });
@@ -76,3 +270,49 @@ pub fn RadarAxis(props: RadarAxisProps) -> Element {
         g { class: "radar-axes", {axis_lines} }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_label() {
+        let label = AxisLabel::wrap("Maintainability and Documentation", 130.0, 2);
+        assert_eq!(label.lines, vec!["Maintainability", "and Documentation"]);
+        assert!(!label.truncated);
+
+        let label = AxisLabel::wrap("Maintainability and Documentation", 130.0, 1);
+        assert_eq!(label.lines.len(), 1);
+        assert!(label.lines[0].ends_with('…'));
+        assert!(label.truncated);
+    }
+
+    #[test]
+    fn test_thinned_labels_do_not_overlap() {
+        use super::super::geometry::Direction;
+
+        let labels = vec![AxisLabel::wrap("Metric", 100.0, 1); 60];
+        let geometry = RadarGeometry::new(
+            (0.0, 0.0),
+            1.0,
+            labels.len(),
+            0.0,
+            Direction::Clockwise,
+            360.0,
+        )
+        .fit(&labels, false, 500.0, 500.0);
+        let shown = thinned(&geometry, &labels);
+        assert!(shown[0]);
+        assert!(shown.contains(&false));
+
+        let boxes = (0..labels.len())
+            .filter(|&index| shown[index])
+            .map(|index| geometry.label_bounds(index, labels[index].size(), false))
+            .collect::<Vec<_>>();
+        for (index, a) in boxes.iter().enumerate() {
+            for b in &boxes[index + 1..] {
+                assert!(a.2 <= b.0 || b.2 <= a.0 || a.3 <= b.1 || b.3 <= a.1);
+            }
+        }
+    }
+}
@@ -1,5 +1,9 @@
+use super::events::PointEvent;
+use super::geometry::RadarGeometry;
+use super::style::CurveFill;
+use super::CurveId;
 use super::{RadarCurve, TooltipData};
-use crate::components::utils::polar_to_cartesian;
+use crate::components::theme::use_theme;
 use dioxus::prelude::*;
 use std::f32::consts::PI;
 
@@ -7,29 +11,99 @@ use std::f32::consts::PI;
 pub mod data_point;
 use self::data_point::DataPoint;
 
+/// How prominently a curve is drawn relative to the others
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum CurveEmphasis {
+    /// No curve is highlighted
+    #[default]
+    Normal,
+    /// This curve is hovered or focused
+    Highlighted,
+    /// Another curve is hovered or focused
+    Dimmed,
+}
+
+impl CurveEmphasis {
+    /// Emphasis of `curve` given the currently highlighted curve, if any
+    pub fn of(curve: &CurveId, highlighted: Option<&CurveId>) -> Self {
+        match highlighted {
+            None => Self::Normal,
+            Some(id) if id == curve => Self::Highlighted,
+            Some(_) => Self::Dimmed,
+        }
+    }
+}
+
 #[derive(Props, PartialEq, Clone)]
 pub struct RadarCurveVisualProps {
     /// Data for the curve
     pub curve: RadarCurve,
-    /// Index of this curve in the parent's curves list
-    pub curve_index: usize,
     /// Labels for each axis
     pub axes: Vec<String>,
-    /// Center X coordinate
-    pub center_x: f32,
-    /// Center Y coordinate
-    pub center_y: f32,
-    /// Radius of the graph
-    pub radius: f32,
+    /// Position and angles of the axes
+    pub geometry: RadarGeometry,
     /// Maximum value on the scale
     pub max_value: f32,
+    /// Id of the pattern definition to fill the curve with, if any
+    #[props(optional)]
+    pub pattern_id: Option<String>,
+    /// Whether the curve is shown; hidden curves are faded out
+    #[props(default = true)]
+    pub visible: bool,
+    /// Whether the values of this curve may be edited
+    #[props(default = true)]
+    pub editable: bool,
+    /// Whether the curve is highlighted, dimmed or drawn normally
+    #[props(default)]
+    pub emphasis: CurveEmphasis,
     /// Shared tooltip state with other curves
     pub tooltip_state: Signal<Option<TooltipData>>,
+    /// Callback for when the pointer enters or leaves one of the data points
+    #[props(optional)]
+    pub on_point_hover: Option<EventHandler<Option<PointEvent>>>,
+    /// Callback for when one of the data points is clicked
+    #[props(optional)]
+    pub on_point_click: Option<EventHandler<PointEvent>>,
+    /// Callback for when the pointer enters (`Some`) or leaves (`None`) the outline
+    #[props(optional)]
+    pub on_hover: Option<EventHandler<Option<CurveId>>>,
+    /// Callback for when the outline of the curve is clicked
+    #[props(optional)]
+    pub on_click: Option<EventHandler<CurveId>>,
 }
 
 /// Component for rendering a single curve in a radar graph
 #[component]
 pub fn RadarCurveVisual(props: RadarCurveVisualProps) -> Element {
+    let theme = use_theme();
+    let color = props.curve.color.unwrap_or_default();
+    let style = &props.curve.style;
+    let (fill, fill_opacity) = match (style.fill, &props.pattern_id) {
+        (CurveFill::None, _) => ("none".to_string(), 0.0),
+        (fill, Some(id)) => (
+            format!("url(#{id})"),
+            fill.opacity(theme.curve_pattern_opacity),
+        ),
+        (fill, None) => (color.to_string(), fill.opacity(theme.curve_fill_opacity)),
+    };
+    let dasharray = style.dash.dasharray();
+
+    // Highlighted curves get a stronger fill and a thicker outline
+    let highlighted = props.emphasis == CurveEmphasis::Highlighted;
+    let (fill_opacity, stroke_width) = if highlighted && fill != "none" {
+        ((fill_opacity + 0.25).min(1.0), style.stroke_width + 1.0)
+    } else if highlighted {
+        (fill_opacity, style.stroke_width + 1.0)
+    } else {
+        (fill_opacity, style.stroke_width)
+    };
+    let class = match (props.visible, props.emphasis) {
+        (false, _) => "radar-curve radar-curve-hidden",
+        (true, CurveEmphasis::Dimmed) => "radar-curve radar-curve-dimmed",
+        (true, _) => "radar-curve",
+    };
+    let curve_id = props.curve.id.clone();
+    let hover_id = props.curve.id.clone();
     let axes_count = props.axes.len();
 
     // Check if the curve has the correct number of data points
@@ -41,27 +115,37 @@ pub fn RadarCurveVisual(props: RadarCurveVisualProps) -> Element {
         };
     }
 
-    let axis_angle_step = 2.0 * PI / axes_count as f32;
+    let geometry = props.geometry;
 
     // Calculate all points first for easier processing
     let points = (0..axes_count)
         .map(|i| {
-            let angle = -PI / 2.0 + i as f32 * axis_angle_step;
             let data_point = &props.curve.data_points[i];
-            let point_radius = props.radius * (data_point.value / props.max_value).clamp(0.0, 1.0);
-            let (x, y) = polar_to_cartesian(point_radius, angle, props.center_x, props.center_y);
-            (x, y)
+            geometry.point(i, (data_point.value / props.max_value).clamp(0.0, 1.0))
         })
         .collect::<Vec<(f32, f32)>>();
 
     // Generate SVG path with curved lines
     let mut path_data = String::new();
 
-    // Start with a move to the first point
-    path_data.push_str(&format!("M{},{} ", points[0].0, points[0].1));
+    // Start with a move to the first point. Partial sweeps have no edge from
+    // the last axis back to the first, so their outline closes through the
+    // center instead.
+    let full_circle = geometry.is_full_circle();
+    if full_circle {
+        path_data.push('M');
+    } else {
+        path_data.push_str(&format!("M{},{} L", geometry.center_x, geometry.center_y));
+    }
+    path_data.push_str(&format!("{},{} ", points[0].0, points[0].1));
 
     // Create curved lines between points
-    for i in 0..axes_count {
+    let segments = if full_circle {
+        axes_count
+    } else {
+        axes_count - 1
+    };
+    for i in 0..segments {
         let next_idx = (i + 1) % axes_count;
         let current = points[i];
         let next = points[next_idx];
@@ -71,9 +155,8 @@ pub fn RadarCurveVisual(props: RadarCurveVisualProps) -> Element {
         let curve_factor = 0.3;
 
         // Calculate tangent points
-        let tangent_angle1 = (-PI / 2.0 + i as f32 * axis_angle_step) % (2.0 * PI) + PI / 2.0;
-        let tangent_angle2 =
-            (-PI / 2.0 + next_idx as f32 * axis_angle_step) % (2.0 * PI) - PI / 2.0;
+        let tangent_angle1 = geometry.tangent(i);
+        let tangent_angle2 = geometry.tangent(next_idx) + PI;
 
         // Distance between points (chord length)
         let dist = ((next.0 - current.0).powi(2) + (next.1 - current.1).powi(2)).sqrt();
@@ -103,34 +186,64 @@ pub fn RadarCurveVisual(props: RadarCurveVisualProps) -> Element {
     let point_circles = (0..axes_count).map(|i| {
         let (x, y) = points[i];
         let data_point = &props.curve.data_points[i];
-        let tooltip_content = format!("{}: {}", data_point.label, data_point.value);
 
         rsx! {
             DataPoint {
                 x: x,
                 y: y,
-                curve_index: props.curve_index,
-                point_index: i,
-                color: props.curve.color.clone(),
-                tooltip_content: tooltip_content,
-                value: data_point.value,
-                tooltip_state: props.tooltip_state
+                content: data_point.tooltip_content(&props.curve, i),
+                color: color,
+                marker: props.curve.style.marker,
+                marker_size: props.curve.style.marker_size,
+                editable: props.editable,
+                tooltip_state: props.tooltip_state,
+                on_hover: props.on_point_hover,
+                on_click: props.on_point_click,
             }
         }
     });
 
     rsx! {
         g {
-            class: "radar-curve",
+            class: "{class}",
+            // Origin for the grow-from-center intro animation
+            style: "transform-origin: {geometry.center_x}px {geometry.center_y}px;",
             // First render the curve path (lowest layer)
             path {
                 d: "{path_data}",
-                fill: "{props.curve.color}",
-                "fill-opacity": "0.3",
-                stroke: "{props.curve.color}",
-                "stroke-width": "2",
+                // The `d` property (unlike the attribute) can be transitioned with CSS
+                style: "d: path('{path_data}');",
+                fill: "{fill}",
+                "fill-opacity": "{fill_opacity}",
+                stroke: "{color}",
+                "stroke-width": "{stroke_width}",
+                "stroke-dasharray": "{dasharray}",
                 "stroke-linejoin": "round",
             }
+            // Wide invisible outline that picks up hover and clicks on the curve
+            path {
+                class: "radar-curve-outline-hit",
+                d: "{path_data}",
+                style: "d: path('{path_data}');",
+                fill: "none",
+                stroke: "transparent",
+                "stroke-width": "10",
+                onmouseenter: move |_| {
+                    if let Some(handler) = &props.on_hover {
+                        handler.call(Some(hover_id.clone()));
+                    }
+                },
+                onmouseleave: move |_| {
+                    if let Some(handler) = &props.on_hover {
+                        handler.call(None);
+                    }
+                },
+                onclick: move |_| {
+                    if let Some(handler) = &props.on_click {
+                        handler.call(curve_id.clone());
+                    }
+                },
+            }
             // Add circles for each data point
             {point_circles}
         }
@@ -1,4 +1,8 @@
+use crate::components::color::Color;
+use crate::components::radar::container::graph::radar::events::PointEvent;
+use crate::components::radar::container::graph::radar::style::MarkerShape;
 use crate::components::radar::container::graph::radar::TooltipData;
+use crate::components::tooltip::TooltipContent;
 use dioxus::prelude::*;
 
 #[derive(Props, PartialEq, Clone)]
@@ -7,101 +11,118 @@ pub struct DataPointProps {
     pub x: f32,
     /// Y coordinate of the data point
     pub y: f32,
-    /// Index of the curve this point belongs to
-    pub curve_index: usize,
-    /// Index of this point within the curve
-    pub point_index: usize,
+    /// Content to display in the tooltip, identifying curve and point
+    pub content: TooltipContent,
     /// Color of the curve/point
-    pub color: String,
-    /// Content to display in the tooltip
-    pub tooltip_content: String,
-    /// Current value of the data point
-    pub value: f32,
+    pub color: Color,
+    /// Shape of the visible marker
+    #[props(default)]
+    pub marker: MarkerShape,
+    /// Size of the visible marker, from the center to the edge
+    #[props(default = 4.0)]
+    pub marker_size: f32,
+    /// Whether clicking the point starts editing its value
+    #[props(default = true)]
+    pub editable: bool,
     /// Shared tooltip state
     pub tooltip_state: Signal<Option<TooltipData>>,
+    /// Callback for when the pointer enters (`Some`) or leaves (`None`) the point
+    #[props(optional)]
+    pub on_hover: Option<EventHandler<Option<PointEvent>>>,
+    /// Callback for when the point is clicked
+    #[props(optional)]
+    pub on_click: Option<EventHandler<PointEvent>>,
 }
 
 /// Component for rendering a data point in a radar curve
 #[component]
 pub fn DataPoint(props: DataPointProps) -> Element {
-    let curve_idx = props.curve_index;
     let x = props.x;
     let y = props.y;
 
-    // Create separate clones for each closure to avoid move errors
-    let tooltip_content_click = props.tooltip_content.clone();
-    let mut tooltip_state_click = props.tooltip_state;
-    let color_click = props.color.clone();
+    // Build the tooltip data for this point with the given pin/edit state
+    let tooltip_data = {
+        let props = props.clone();
+        move |pinned: bool, editing: bool| TooltipData {
+            content: props.content.clone(),
+            x,
+            y,
+            color: props.color,
+            pinned,
+            editing: editing && props.editable,
+            editable: props.editable,
+        }
+    };
+    let tooltip_data_enter = tooltip_data.clone();
 
-    let tooltip_content_enter = props.tooltip_content.clone();
+    let mut tooltip_state_click = props.tooltip_state;
     let mut tooltip_state_enter = props.tooltip_state;
-    let color_enter = props.color.clone();
-
     let mut tooltip_state_leave = props.tooltip_state;
 
-    // Clone color once more for the render function
-    let color_render = props.color.clone();
+    let on_hover = props.on_hover;
+    let on_click = props.on_click;
+
+    // Darker outline so points stay visible on top of their own filled area
+    let color_render = props.color;
+    let outline = props.color.darken(0.3);
+    let marker_path = props.marker.path(x, y, props.marker_size);
+    // Keep the hit area comfortably larger than the marker
+    let hit_radius = f32::max(10.0, props.marker_size + 6.0);
+
+    // Editable points invite clicking, read-only points only reveal details
+    let hitarea_class = if props.editable {
+        "data-point-hitarea cursor-pointer"
+    } else {
+        "data-point-hitarea cursor-help"
+    };
 
     // Click handler that always pins on first click, unpins on second
     let handle_click = move |_| {
         let current_tooltip = tooltip_state_click.read().clone();
+        let data = tooltip_data(false, false);
 
         // Check if this is the same data point and it's already pinned
         let is_same_point_and_pinned = current_tooltip.as_ref().is_some_and(|t| {
-            t.curve_index == curve_idx && t.point_index == props.point_index && t.pinned
+            t.content.curve_id == data.content.curve_id
+                && t.content.point_index == data.content.point_index
+                && t.pinned
         });
 
+        if let Some(handler) = &on_click {
+            handler.call(data.point_event());
+        }
+
         if is_same_point_and_pinned {
             // If it's already pinned, unpin it
-            tooltip_state_click.set(Some(TooltipData {
-                curve_index: curve_idx,
-                point_index: props.point_index,
-                label: tooltip_content_click.clone(),
-                value: props.value,
-                x,
-                y,
-                color: color_click.clone(),
-                pinned: false,
-                editing: false,
-            }));
+            tooltip_state_click.set(Some(data));
         } else {
-            // Otherwise, pin it and immediately enter edit mode (overriding any other pinned tooltip)
-            tooltip_state_click.set(Some(TooltipData {
-                curve_index: curve_idx,
-                point_index: props.point_index,
-                label: tooltip_content_click.clone(),
-                value: props.value,
-                x,
-                y,
-                color: color_click.clone(),
-                pinned: true,
-                editing: true,
-            }));
+            // Otherwise, pin it and immediately enter edit mode if editable (overriding any other pinned tooltip)
+            tooltip_state_click.set(Some(tooltip_data(true, true)));
         }
     };
 
     let handle_mouseenter = move |_| {
+        let data = tooltip_data_enter(false, false);
+
+        if let Some(handler) = &on_hover {
+            handler.call(Some(data.point_event()));
+        }
+
         // Only set tooltip if there isn't already a pinned one
         if tooltip_state_enter
             .read()
             .as_ref()
             .is_none_or(|t| !t.pinned)
         {
-            tooltip_state_enter.set(Some(TooltipData {
-                curve_index: curve_idx,
-                point_index: props.point_index,
-                label: tooltip_content_enter.clone(),
-                value: props.value,
-                x,
-                y,
-                color: color_enter.clone(),
-                pinned: false,
-                editing: false,
-            }));
+            tooltip_state_enter.set(Some(data));
         }
     };
 
     let handle_mouseleave = move |_| {
+        if let Some(handler) = &on_hover {
+            handler.call(None);
+        }
+
         // Only hide tooltip if it's not pinned
         let is_pinned = tooltip_state_leave
             .read()
@@ -115,21 +136,24 @@ pub fn DataPoint(props: DataPointProps) -> Element {
     rsx! {
         g {
             class: "data-point",
-            // Visible circle - just for display
-            circle {
-                cx: "{x}",
-                cy: "{y}",
-                r: "4",
-                fill: "{color_render}",
-                class: "data-point-circle",
-            },
+            // Visible marker - just for display
+            if let Some(marker_path) = marker_path {
+                path {
+                    d: "{marker_path}",
+                    style: "d: path('{marker_path}');",
+                    fill: "{color_render}",
+                    stroke: "{outline}",
+                    "stroke-width": "1",
+                    class: "data-point-circle",
+                }
+            }
             // Invisible larger circle for all interactions
             circle {
                 cx: "{x}",
                 cy: "{y}",
-                r: "10",
+                r: "{hit_radius}",
                 fill: "transparent",
-                class: "data-point-hitarea",
+                class: "{hitarea_class}",
                 onmouseenter: handle_mouseenter,
                 onmouseleave: handle_mouseleave,
                 onclick: handle_click,
@@ -0,0 +1,85 @@
+//! Typed events emitted by the radar graph components
+
+use super::CurveId;
+
+/// Identifies a single data point, e.g. the one currently pinned
+#[derive(Clone, Debug, PartialEq)]
+pub struct PointSelection {
+    /// Stable identifier of the curve the point belongs to
+    pub curve_id: CurveId,
+    /// Index of the data point within the curve
+    pub point_index: usize,
+}
+
+/// Event emitted when the user edits the value of a data point
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValueChange {
+    /// Stable identifier of the edited curve
+    pub curve_id: CurveId,
+    /// Position of the curve in the curve list of the emitting component.
+    ///
+    /// Kept for callers that still address curves by index; prefer `curve_id`
+    /// since the position shifts when curves are hidden, reordered or removed.
+    pub curve_index: usize,
+    /// Index of the data point within the curve
+    pub point_index: usize,
+    /// The new value
+    pub value: f32,
+}
+
+/// Migration path for handlers written against the old `(curve, point, value)` tuple
+impl From<ValueChange> for (usize, usize, f32) {
+    fn from(change: ValueChange) -> Self {
+        (change.curve_index, change.point_index, change.value)
+    }
+}
+
+/// Information about a data point the user interacted with
+#[derive(Clone, Debug, PartialEq)]
+pub struct PointEvent {
+    /// Stable identifier of the curve the point belongs to
+    pub curve_id: CurveId,
+    /// Index of the data point within the curve
+    pub point_index: usize,
+    /// Name of the axis the point sits on
+    pub axis: String,
+    /// Current value of the data point
+    pub value: f32,
+}
+
+impl PointEvent {
+    /// Returns the selection identifying this point
+    pub fn selection(&self) -> PointSelection {
+        PointSelection {
+            curve_id: self.curve_id.clone(),
+            point_index: self.point_index,
+        }
+    }
+}
+
+/// Event emitted when a tooltip gets pinned or unpinned
+#[derive(Clone, Debug, PartialEq)]
+pub struct TooltipPinEvent {
+    /// The data point the tooltip belongs to
+    pub point: PointEvent,
+    /// Whether the tooltip is now pinned
+    pub pinned: bool,
+}
+
+/// Event emitted when a curve is shown or hidden
+#[derive(Clone, Debug, PartialEq)]
+pub struct CurveToggleEvent {
+    /// Stable identifier of the toggled curve
+    pub curve_id: CurveId,
+    /// Whether the curve is visible after the toggle
+    pub visible: bool,
+}
+
+/// Event emitted when an axis label is clicked
+#[derive(Clone, Debug, PartialEq)]
+pub struct AxisEvent {
+    /// Index of the axis
+    pub axis_index: usize,
+    /// Name of the axis
+    pub axis: String,
+}
@@ -0,0 +1,465 @@
+//! Geometry shared by all parts of a radar graph
+//!
+//! Grid, axes, curves, labels and hit areas all take their positions from one
+//! [`RadarGeometry`], so they stay aligned for any start angle, direction and
+//! sweep.
+
+use super::axis::AxisLabel;
+use crate::components::utils::polar_to_cartesian;
+use std::f32::consts::PI;
+use std::ops::Range;
+
+/// Distance between the end of an axis and its label
+const LABEL_GAP: f32 = 8.0;
+/// Space kept free at the edges of the drawing area
+const MARGIN: f32 = 4.0;
+/// Steps of the search for the largest radius that fits
+const FIT_STEPS: usize = 24;
+
+/// Direction in which the axes follow each other
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Direction {
+    #[default]
+    Clockwise,
+    CounterClockwise,
+}
+
+impl Direction {
+    /// Sign of the angle between neighboring axes in screen coordinates,
+    /// where positive angles turn clockwise
+    fn sign(self) -> f32 {
+        match self {
+            Self::Clockwise => 1.0,
+            Self::CounterClockwise => -1.0,
+        }
+    }
+}
+
+/// How a label is aligned to its anchor point at the end of an axis
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Alignment {
+    /// Extends towards positive coordinates: right, down
+    After,
+    /// Extends towards negative coordinates: left, up
+    Before,
+    Centered,
+}
+
+impl Alignment {
+    /// Alignment along one dimension for the direction component `component`
+    fn of(component: f32) -> Self {
+        if component > 0.1 {
+            Self::After
+        } else if component < -0.1 {
+            Self::Before
+        } else {
+            Self::Centered
+        }
+    }
+
+    /// Start of a block of `size` placed at `anchor`
+    fn start(self, anchor: f32, size: f32) -> f32 {
+        match self {
+            Self::After => anchor,
+            Self::Before => anchor - size,
+            Self::Centered => anchor - size / 2.0,
+        }
+    }
+}
+
+/// Position of an axis label block
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AxisLabelPosition {
+    pub left: f32,
+    pub top: f32,
+    /// X coordinate of the text lines, matching `anchor`
+    pub text_x: f32,
+    /// SVG `text-anchor` of the lines
+    pub anchor: &'static str,
+}
+
+/// Center, radius and axis angles of a radar graph
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RadarGeometry {
+    pub center_x: f32,
+    pub center_y: f32,
+    pub radius: f32,
+    /// Angle of the first axis in radians
+    start: f32,
+    /// Swept angle in radians, at most a full circle
+    sweep: f32,
+    /// Signed angle between neighboring axes in radians
+    step: f32,
+    direction: Direction,
+    axes_count: usize,
+    /// Width of the band between the plot and the axis labels that holds
+    /// the names of axis groups
+    ring: f32,
+}
+
+impl RadarGeometry {
+    /// Geometry for `axes_count` axes around `(center_x, center_y)`.
+    ///
+    /// `start_angle` is in degrees clockwise from 12 o'clock and `sweep` is
+    /// the angle in degrees the axes are spread over. A full circle leaves
+    /// the same gap between the last and the first axis as between all
+    /// others, a partial sweep puts the last axis at its end.
+    pub fn new(
+        center: (f32, f32),
+        radius: f32,
+        axes_count: usize,
+        start_angle: f32,
+        direction: Direction,
+        sweep: f32,
+    ) -> Self {
+        let sweep = sweep.clamp(1.0, 360.0).to_radians();
+        let gaps = if sweep >= 2.0 * PI - f32::EPSILON {
+            axes_count
+        } else {
+            axes_count.saturating_sub(1)
+        };
+        let step = if gaps == 0 {
+            0.0
+        } else {
+            direction.sign() * sweep / gaps as f32
+        };
+
+        Self {
+            center_x: center.0,
+            center_y: center.1,
+            radius,
+            start: -PI / 2.0 + start_angle.to_radians(),
+            sweep,
+            step,
+            direction,
+            axes_count,
+            ring: 0.0,
+        }
+    }
+
+    /// The same geometry with a band of `width` between the plot and the
+    /// axis labels
+    pub fn with_ring(self, width: f32) -> Self {
+        Self {
+            ring: width,
+            ..self
+        }
+    }
+
+    /// This geometry moved and scaled to the largest radius for which the
+    /// plot, the ring and all `labels` fit inside `width` × `height`, see
+    /// [`label_bounds`](Self::label_bounds) for `rotated`.
+    ///
+    /// The plot is centered on its bounding box, so e.g. a semicircle uses
+    /// the whole height. The radius never drops below a sixth of the smaller
+    /// dimension, so extreme labels can't squash the chart.
+    pub fn fit(self, labels: &[AxisLabel], rotated: bool, width: f32, height: f32) -> Self {
+        let (min_x, min_y, max_x, max_y) = self.unit_bounds();
+        let at_radius = |radius: f32| {
+            let outer = radius + self.ring;
+            Self {
+                center_x: width / 2.0 - outer * (min_x + max_x) / 2.0,
+                center_y: height / 2.0 - outer * (min_y + max_y) / 2.0,
+                radius,
+                ..self
+            }
+        };
+
+        let inside = |left: f32, top: f32, right: f32, bottom: f32| {
+            left >= MARGIN && top >= MARGIN && right <= width - MARGIN && bottom <= height - MARGIN
+        };
+        let fits = |geometry: &Self| {
+            let (center_x, center_y, outer) = (
+                geometry.center_x,
+                geometry.center_y,
+                geometry.radius + geometry.ring,
+            );
+            inside(
+                center_x + outer * min_x,
+                center_y + outer * min_y,
+                center_x + outer * max_x,
+                center_y + outer * max_y,
+            ) && labels.iter().enumerate().all(|(index, label)| {
+                let (left, top, right, bottom) =
+                    geometry.label_bounds(index, label.size(), rotated);
+                inside(left, top, right, bottom)
+            })
+        };
+
+        // Fitting only gets harder with a growing radius, so bisect
+        let mut low = f32::min(width, height) / 6.0;
+        let mut high = f32::max(width, height);
+        if !fits(&at_radius(low)) {
+            return at_radius(low);
+        }
+        for _ in 0..FIT_STEPS {
+            let middle = (low + high) / 2.0;
+            if fits(&at_radius(middle)) {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        at_radius(low)
+    }
+
+    /// Whether the axes go all the way around
+    pub fn is_full_circle(&self) -> bool {
+        self.sweep >= 2.0 * PI - f32::EPSILON
+    }
+
+    /// Angle of the axis at `index` in radians
+    pub fn angle(&self, index: usize) -> f32 {
+        self.start + index as f32 * self.step
+    }
+
+    /// Direction in which a curve leaves the axis at `index` towards the next
+    /// axis, in radians
+    pub fn tangent(&self, index: usize) -> f32 {
+        self.angle(index) + self.direction.sign() * PI / 2.0
+    }
+
+    /// Point on the axis at `index` at `ratio` of the radius
+    pub fn point(&self, index: usize, ratio: f32) -> (f32, f32) {
+        self.point_at(self.radius * ratio, self.angle(index))
+    }
+
+    /// Point at `distance` from the center in the direction of `angle`
+    pub fn point_at(&self, distance: f32, angle: f32) -> (f32, f32) {
+        polar_to_cartesian(distance, angle, self.center_x, self.center_y)
+    }
+
+    /// SVG path of the arc swept by the axes at `ratio` of the radius, used
+    /// for the grid of partial sweeps
+    pub fn arc_path(&self, ratio: f32) -> String {
+        let radius = self.radius * ratio;
+        let (start_x, start_y) = self.point_at(radius, self.start);
+        let end = self.start + self.direction.sign() * self.sweep;
+        let (end_x, end_y) = self.point_at(radius, end);
+        let large_arc = u8::from(self.sweep > PI);
+        let clockwise = u8::from(self.direction == Direction::Clockwise);
+        format!("M{start_x},{start_y} A{radius},{radius} 0 {large_arc} {clockwise} {end_x},{end_y}")
+    }
+
+    /// Position of a label block of `size` (width, height) just beyond the
+    /// end of the axis at `index`, extending away from the chart
+    pub fn label_position(&self, index: usize, size: (f32, f32)) -> AxisLabelPosition {
+        let (width, height) = size;
+        let angle = self.angle(index);
+        let (anchor_x, anchor_y) = self.label_anchor(index);
+        let horizontal = Alignment::of(angle.cos());
+        let left = horizontal.start(anchor_x, width);
+        let top = Alignment::of(angle.sin()).start(anchor_y, height);
+        let (anchor, text_x) = match horizontal {
+            Alignment::After => ("start", left),
+            Alignment::Before => ("end", left + width),
+            Alignment::Centered => ("middle", left + width / 2.0),
+        };
+
+        AxisLabelPosition {
+            left,
+            top,
+            text_x,
+            anchor,
+        }
+    }
+
+    /// Bounding box (left, top, right, bottom) of a label of `size` at the
+    /// axis at `index`. Rotated labels run outward along their axis, others
+    /// are placed as in [`label_position`](Self::label_position).
+    pub fn label_bounds(
+        &self,
+        index: usize,
+        size: (f32, f32),
+        rotated: bool,
+    ) -> (f32, f32, f32, f32) {
+        let (width, height) = size;
+        if !rotated {
+            let position = self.label_position(index, size);
+            return (
+                position.left,
+                position.top,
+                position.left + width,
+                position.top + height,
+            );
+        }
+
+        let angle = self.angle(index);
+        let (start_x, start_y) = self.label_anchor(index);
+        let (end_x, end_y) = self.point_at(self.label_distance() + width, angle);
+        // The text is `height` thick across the axis
+        let across_x = (angle.sin() * height / 2.0).abs();
+        let across_y = (angle.cos() * height / 2.0).abs();
+        (
+            start_x.min(end_x) - across_x,
+            start_y.min(end_y) - across_y,
+            start_x.max(end_x) + across_x,
+            start_y.max(end_y) + across_y,
+        )
+    }
+
+    /// Distance from the center at which the axis labels start
+    fn label_distance(&self) -> f32 {
+        self.radius + self.ring + LABEL_GAP
+    }
+
+    /// Point just beyond the end of the axis at `index` where its label starts
+    pub fn label_anchor(&self, index: usize) -> (f32, f32) {
+        self.point_at(self.label_distance(), self.angle(index))
+    }
+
+    /// Distance between the label anchors of two neighboring axes
+    pub fn label_spacing(&self) -> f32 {
+        self.label_distance() * self.step.abs()
+    }
+
+    /// Start and end angle of the sector around the axes in `axes`. Sectors
+    /// reach halfway to the neighboring axes, but not past the ends of a
+    /// partial sweep.
+    pub fn sector_angles(&self, axes: &Range<usize>) -> (f32, f32) {
+        let half_step = self.step / 2.0;
+        let full_circle = self.is_full_circle();
+        let start = if full_circle || axes.start > 0 {
+            self.angle(axes.start) - half_step
+        } else {
+            self.angle(axes.start)
+        };
+        let last = axes.end.saturating_sub(1);
+        let end = if full_circle || axes.end < self.axes_count {
+            self.angle(last) + half_step
+        } else {
+            self.angle(last)
+        };
+        (start, end)
+    }
+
+    /// SVG path of the arc at `distance` from the center between two angles,
+    /// drawn from `start` to `end`
+    pub fn arc(&self, start: f32, end: f32, distance: f32) -> String {
+        // Two halves keep each arc below half a circle, so a sector all the
+        // way around still draws
+        let middle = (start + end) / 2.0;
+        let sweep_flag = u8::from(end > start);
+        let (start_x, start_y) = self.point_at(distance, start);
+        let (middle_x, middle_y) = self.point_at(distance, middle);
+        let (end_x, end_y) = self.point_at(distance, end);
+        format!(
+            "M{start_x},{start_y} \
+             A{distance},{distance} 0 0 {sweep_flag} {middle_x},{middle_y} \
+             A{distance},{distance} 0 0 {sweep_flag} {end_x},{end_y}"
+        )
+    }
+
+    /// SVG path of the part of the ring between `inner` and `outer` distance
+    /// from the center that belongs to the sector of `axes`
+    pub fn sector_path(&self, axes: &Range<usize>, inner: f32, outer: f32) -> String {
+        let (start, end) = self.sector_angles(axes);
+        let (inner_x, inner_y) = self.point_at(inner, end);
+        let outer_arc = self.arc(start, end, outer);
+        let inner_arc = self.arc(end, start, inner);
+        // The inner arc is drawn backwards, so it continues where the line ends
+        let inner_arc = inner_arc.split_once(' ').map_or("", |(_, arcs)| arcs);
+        format!("{outer_arc} L{inner_x},{inner_y} {inner_arc} Z")
+    }
+
+    /// Outer edge of the ring, where the axis labels begin
+    pub fn ring_outer(&self) -> f32 {
+        self.radius + self.ring
+    }
+
+    /// Bounding box (min x, min y, max x, max y) of the plot at radius 1
+    /// around the origin
+    fn unit_bounds(&self) -> (f32, f32, f32, f32) {
+        if self.is_full_circle() {
+            return (-1.0, -1.0, 1.0, 1.0);
+        }
+
+        // The wedge reaches from the center to its two ends and bulges out
+        // at every 90° direction it passes
+        let end = self.start + self.direction.sign() * self.sweep;
+        let passed = (0..4)
+            .map(|quarter| quarter as f32 * PI / 2.0)
+            .filter(|angle| {
+                let offset = (self.direction.sign() * (angle - self.start)).rem_euclid(2.0 * PI);
+                offset <= self.sweep
+            });
+        [self.start, end]
+            .into_iter()
+            .chain(passed)
+            .map(|angle| (angle.cos(), angle.sin()))
+            .fold(
+                (0.0, 0.0, 0.0, 0.0),
+                |(min_x, min_y, max_x, max_y), (x, y)| {
+                    (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y))
+                },
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_axis_angles() {
+        let geometry = RadarGeometry::new((0.0, 0.0), 1.0, 4, 0.0, Direction::Clockwise, 360.0);
+        assert_eq!(geometry.angle(0), -PI / 2.0);
+        assert_eq!(geometry.angle(1), 0.0);
+
+        let geometry =
+            RadarGeometry::new((0.0, 0.0), 1.0, 4, 90.0, Direction::CounterClockwise, 360.0);
+        assert_eq!(geometry.angle(0), 0.0);
+        assert_eq!(geometry.angle(1), -PI / 2.0);
+
+        // A semicircle puts the last axis at its end
+        let geometry = RadarGeometry::new((0.0, 0.0), 1.0, 3, -90.0, Direction::Clockwise, 180.0);
+        assert_eq!(geometry.angle(0), -PI);
+        assert_eq!(geometry.angle(2), 0.0);
+    }
+
+    #[test]
+    fn test_fit_shrinks_for_long_labels() {
+        let fit = |text: &str| {
+            let labels = vec![AxisLabel::wrap(text, 130.0, 2); 4];
+            RadarGeometry::new(
+                (0.0, 0.0),
+                1.0,
+                labels.len(),
+                0.0,
+                Direction::Clockwise,
+                360.0,
+            )
+            .fit(&labels, false, 500.0, 500.0)
+        };
+        let short = fit("A");
+        let long = fit("Maintainability and Documentation");
+        assert!(long.radius < short.radius);
+        assert!(short.radius <= 246.0);
+        assert_eq!((short.center_x, short.center_y), (250.0, 250.0));
+    }
+
+    #[test]
+    fn test_fit_semicircle_uses_full_height() {
+        let labels = vec![AxisLabel::wrap("A", 130.0, 2); 3];
+        let full = RadarGeometry::new(
+            (0.0, 0.0),
+            1.0,
+            labels.len(),
+            0.0,
+            Direction::Clockwise,
+            360.0,
+        )
+        .fit(&labels, false, 500.0, 300.0);
+        let half = RadarGeometry::new(
+            (0.0, 0.0),
+            1.0,
+            labels.len(),
+            -90.0,
+            Direction::Clockwise,
+            180.0,
+        )
+        .fit(&labels, false, 500.0, 300.0);
+        assert!(half.radius > full.radius);
+        assert!(half.center_y > 150.0);
+    }
+}
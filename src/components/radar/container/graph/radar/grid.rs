@@ -1,49 +1,77 @@
+use super::geometry::RadarGeometry;
+use super::sectors::Sector;
+use crate::components::theme::use_theme;
 use dioxus::prelude::*;
 
 #[derive(Props, PartialEq, Clone)]
 pub struct RadarGridProps {
-    /// Center X coordinate
-    pub center_x: f32,
-    /// Center Y coordinate
-    pub center_y: f32,
-    /// Radius of the graph
-    pub radius: f32,
+    /// Position and angles of the axes
+    pub geometry: RadarGeometry,
     /// Maximum value on the scale
     pub max_value: f32,
     /// Number of grid levels to display
     #[props(default = 5)]
     pub grid_levels: u32,
+    /// Sectors of axis groups, shaded behind the grid
+    #[props(default)]
+    pub sectors: Vec<Sector>,
 }
 
-/// Component for rendering the grid circles of a radar graph
+/// Component for rendering the grid circles of a radar graph, or arcs when
+/// the axes don't go all the way around
 #[component]
 pub fn RadarGrid(props: RadarGridProps) -> Element {
+    let theme = use_theme();
+    let geometry = props.geometry;
+
     let grid_circles = (1..=props.grid_levels).map(|level| {
-        let level_radius = props.radius * level as f32 / props.grid_levels as f32;
-        let level_value = props.max_value * level as f32 / props.grid_levels as f32;
+        let ratio = level as f32 / props.grid_levels as f32;
+        let level_value = props.max_value * ratio;
+        // Values are written along the first axis
+        let (label_x, label_y) = geometry.point(0, ratio);
 
         rsx! {
-            circle {
-                cx: "{props.center_x}",
-                cy: "{props.center_y}",
-                r: "{level_radius}",
-                fill: "none",
-                stroke: "#dddddd",
-                class: "dark:stroke-gray-600",
-                "stroke-width": "1"
+            if geometry.is_full_circle() {
+                circle {
+                    cx: "{geometry.center_x}",
+                    cy: "{geometry.center_y}",
+                    r: "{geometry.radius * ratio}",
+                    fill: "none",
+                    stroke: "{theme.grid}",
+                    "stroke-width": "1"
+                }
+            } else {
+                path {
+                    d: "{geometry.arc_path(ratio)}",
+                    fill: "none",
+                    stroke: "{theme.grid}",
+                    "stroke-width": "1"
+                }
             }
             text {
-                x: "{props.center_x}",
-                y: "{props.center_y - level_radius}",
+                x: "{label_x}",
+                y: "{label_y}",
                 "text-anchor": "middle",
                 "font-size": "10px",
-                class: "fill-gray-600 dark:fill-gray-300 font-medium dark:font-semibold",
+                fill: "{theme.grid_label}",
+                "font-weight": if theme.is_dark { "600" } else { "500" },
                 "{level_value}"
             }
         }
     });
 
+    let shading = props.sectors.iter().map(|sector| {
+        rsx! {
+            path {
+                key: "{sector.axes.start}",
+                d: "{geometry.sector_path(&sector.axes, 0.0, geometry.radius)}",
+                fill: "{sector.color(theme.is_dark)}",
+                "fill-opacity": "0.08",
+            }
+        }
+    });
+
     rsx! {
-        g { class: "radar-grid", {grid_circles} }
+        g { class: "radar-grid", {shading} {grid_circles} }
     }
 }
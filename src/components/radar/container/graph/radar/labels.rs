@@ -0,0 +1,222 @@
+//! Placement of text labels around chart points without overlaps
+//!
+//! Label sizes are estimated with [`estimate_width`](crate::components::utils::estimate_width),
+//! which is good enough to keep labels from piling up on each other.
+
+use super::{CurveId, RadarCurve};
+
+/// Number of positions tried per label before giving up and overlapping
+const ATTEMPTS: usize = 12;
+
+/// Which data points get an always-on value label
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ValueLabels {
+    /// Values are only shown in tooltips
+    #[default]
+    None,
+    /// Every point of every visible curve
+    All,
+    /// Every point of the focused curve
+    Focused,
+    /// The highest and lowest value on each axis
+    MinMax,
+}
+
+/// Data points that get a value label in `mode`, as pairs of the position
+/// in `curves` and the point index
+pub fn value_label_points(
+    curves: &[&RadarCurve],
+    mode: ValueLabels,
+    focused: Option<&CurveId>,
+) -> Vec<(usize, usize)> {
+    let all_points =
+        |index: usize| (0..curves[index].data_points.len()).map(move |point| (index, point));
+    match mode {
+        ValueLabels::None => Vec::new(),
+        ValueLabels::All => (0..curves.len()).flat_map(all_points).collect(),
+        ValueLabels::Focused => curves
+            .iter()
+            .position(|curve| Some(&curve.id) == focused)
+            .into_iter()
+            .flat_map(all_points)
+            .collect(),
+        ValueLabels::MinMax => {
+            let axes_count = curves
+                .iter()
+                .map(|c| c.data_points.len())
+                .max()
+                .unwrap_or(0);
+            let mut points = Vec::new();
+            for axis in 0..axes_count {
+                let values = curves
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, curve)| Some((index, curve.data_points.get(axis)?.value)))
+                    .collect::<Vec<_>>();
+                let max = values.iter().max_by(|a, b| a.1.total_cmp(&b.1));
+                let min = values.iter().min_by(|a, b| a.1.total_cmp(&b.1));
+                if let Some(&(index, _)) = max {
+                    points.push((index, axis));
+                }
+                // With all values equal both labels would sit on the same spot
+                if let Some(&(index, _)) = min.filter(|min| Some(min.1) != max.map(|max| max.1)) {
+                    points.push((index, axis));
+                }
+            }
+            points
+        }
+    }
+}
+
+/// Axis-aligned box occupied by a label
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LabelBox {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl LabelBox {
+    /// Returns true if the boxes share any area
+    pub fn overlaps(&self, other: &LabelBox) -> bool {
+        self.x < other.x + other.width
+            && other.x < self.x + self.width
+            && self.y < other.y + other.height
+            && other.y < self.y + self.height
+    }
+
+    fn inside(&self, width: f32, height: f32) -> bool {
+        self.x >= 0.0
+            && self.y >= 0.0
+            && self.x + self.width <= width
+            && self.y + self.height <= height
+    }
+}
+
+/// Where to draw a label: `x`/`y` are the text anchor point with the text
+/// vertically centered on `y`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PlacedLabel {
+    pub x: f32,
+    pub y: f32,
+    /// Value for the SVG `text-anchor` attribute
+    pub anchor: &'static str,
+}
+
+/// Places labels one after another, avoiding the ones already placed
+pub struct LabelPlacer {
+    width: f32,
+    height: f32,
+    placed: Vec<LabelBox>,
+}
+
+impl LabelPlacer {
+    /// Create a placer for a drawing area of the given size
+    pub fn new(width: f32, height: f32) -> Self {
+        Self {
+            width,
+            height,
+            placed: Vec::new(),
+        }
+    }
+
+    /// Place a label of the given size next to the point `(x, y)`.
+    ///
+    /// The label is pushed away from the point along `angle` (radians, the
+    /// direction from the chart center to the point) and then nudged sideways
+    /// until it neither overlaps a previous label nor leaves the drawing area.
+    /// If no free spot is found, the first candidate is used.
+    pub fn place(&mut self, x: f32, y: f32, angle: f32, width: f32, height: f32) -> PlacedLabel {
+        let (cos, sin) = (angle.cos(), angle.sin());
+        let anchor = if cos > 0.3 {
+            "start"
+        } else if cos < -0.3 {
+            "end"
+        } else {
+            "middle"
+        };
+
+        let candidate = |attempt: usize| {
+            // Alternate sideways nudges while slowly moving outward
+            let distance = 6.0 + (attempt / 3) as f32 * 4.0;
+            let nudge = match attempt % 3 {
+                0 => 0.0,
+                1 => height,
+                _ => -height,
+            } * (1 + attempt / 3) as f32;
+            let label = PlacedLabel {
+                x: x + cos * distance,
+                y: y + sin * distance + nudge,
+                anchor,
+            };
+            (label, label_box(&label, width, height))
+        };
+
+        let (label, area) = (0..ATTEMPTS)
+            .map(candidate)
+            .find(|(_, area)| {
+                area.inside(self.width, self.height)
+                    && !self.placed.iter().any(|placed| placed.overlaps(area))
+            })
+            .unwrap_or_else(|| candidate(0));
+        self.placed.push(area);
+        label
+    }
+}
+
+/// Box covered by a label drawn at `label`
+fn label_box(label: &PlacedLabel, width: f32, height: f32) -> LabelBox {
+    let left = match label.anchor {
+        "start" => label.x,
+        "end" => label.x - width,
+        _ => label.x - width / 2.0,
+    };
+    LabelBox {
+        x: left,
+        y: label.y - height / 2.0,
+        width,
+        height,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::radar::DataPoint;
+
+    #[test]
+    fn test_min_max_value_labels() {
+        let a = RadarCurve::new(
+            "a",
+            vec![DataPoint::new("x", 1.0), DataPoint::new("y", 5.0)],
+        );
+        let b = RadarCurve::new(
+            "b",
+            vec![DataPoint::new("x", 3.0), DataPoint::new("y", 2.0)],
+        );
+        let points = value_label_points(&[&a, &b], ValueLabels::MinMax, None);
+        assert_eq!(points, vec![(1, 0), (0, 0), (0, 1), (1, 1)]);
+
+        // A tie on the first axis gets a single label
+        let c = RadarCurve::new(
+            "c",
+            vec![DataPoint::new("x", 3.0), DataPoint::new("y", 2.0)],
+        );
+        let tied = value_label_points(&[&b, &c], ValueLabels::MinMax, None);
+        assert_eq!(tied.iter().filter(|point| point.1 == 0).count(), 1);
+        assert_eq!(tied.len(), 2);
+
+        let focused = value_label_points(&[&a, &b], ValueLabels::Focused, Some(&b.id));
+        assert_eq!(focused, vec![(1, 0), (1, 1)]);
+    }
+
+    #[test]
+    fn test_labels_do_not_overlap() {
+        let mut placer = LabelPlacer::new(500.0, 500.0);
+        let first = placer.place(300.0, 250.0, 0.0, 60.0, 14.0);
+        let second = placer.place(300.0, 252.0, 0.0, 60.0, 14.0);
+        assert_eq!(first.anchor, "start");
+        assert!(!label_box(&first, 60.0, 14.0).overlaps(&label_box(&second, 60.0, 14.0)));
+    }
+}
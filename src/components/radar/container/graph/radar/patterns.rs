@@ -0,0 +1,73 @@
+use super::style::FillPattern;
+use super::RadarCurve;
+use dioxus::prelude::*;
+
+/// Size of a pattern tile in user units
+const TILE: f32 = 8.0;
+
+/// Id of the pattern definition for the curve at `index`
+///
+/// Ids are based on the position instead of the curve id, which may contain
+/// characters that are not allowed in an XML id.
+pub fn pattern_id(prefix: &str, index: usize) -> String {
+    format!("{prefix}-{index}")
+}
+
+#[derive(Props, PartialEq, Clone)]
+pub struct PatternDefsProps {
+    /// Prefix making the ids unique within the document
+    pub prefix: String,
+    /// Curves to generate patterns for; curves without a pattern are skipped
+    pub curves: Vec<RadarCurve>,
+}
+
+/// Component generating the `<defs>` with one pattern per patterned curve
+///
+/// Use [`pattern_id`] with the same prefix and curve index to reference them.
+#[component]
+pub fn PatternDefs(props: PatternDefsProps) -> Element {
+    let patterns = props
+        .curves
+        .iter()
+        .enumerate()
+        .filter_map(|(index, curve)| {
+            let pattern = curve.style.pattern?;
+            let id = pattern_id(&props.prefix, index);
+            let color = curve.color.unwrap_or_default();
+
+            let content = match pattern {
+                FillPattern::DiagonalHatch => rsx! {
+                    line { x1: "0", y1: "0", x2: "0", y2: "{TILE}", stroke: "{color}", "stroke-width": "2" }
+                },
+                FillPattern::CrossHatch => rsx! {
+                    line { x1: "0", y1: "0", x2: "0", y2: "{TILE}", stroke: "{color}", "stroke-width": "1.5" }
+                    line { x1: "0", y1: "0", x2: "{TILE}", y2: "0", stroke: "{color}", "stroke-width": "1.5" }
+                },
+                FillPattern::Dots => rsx! {
+                    circle { cx: "{TILE / 2.0}", cy: "{TILE / 2.0}", r: "1.5", fill: "{color}" }
+                },
+            };
+
+            // Lines are rotated so hatches run diagonally
+            let transform = match pattern {
+                FillPattern::Dots => "",
+                _ => "rotate(45)",
+            };
+
+            Some(rsx! {
+                pattern {
+                    key: "{id}",
+                    id: "{id}",
+                    width: "{TILE}",
+                    height: "{TILE}",
+                    "patternUnits": "userSpaceOnUse",
+                    "patternTransform": "{transform}",
+                    {content}
+                }
+            })
+        });
+
+    rsx! {
+        defs { {patterns} }
+    }
+}
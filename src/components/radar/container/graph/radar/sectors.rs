@@ -0,0 +1,207 @@
+//! Groups of neighboring axes, drawn as labeled sectors of the chart
+
+use super::axis::AxisLabel;
+use super::geometry::RadarGeometry;
+use crate::components::color::Color;
+use crate::components::theme::use_theme;
+use crate::components::utils::unique_id;
+use dioxus::prelude::*;
+use std::ops::Range;
+
+/// Width of the ring holding the group names
+pub const RING_WIDTH: f32 = 18.0;
+/// Neutral shades the groups cycle through on light and dark backgrounds,
+/// kept gray so sectors can't be mistaken for curves
+const SHADES: [u32; 3] = [0x5B6573, 0x97A0AB, 0x7A8491];
+const DARK_SHADES: [u32; 3] = [0xC9D0D8, 0x808A96, 0xA5AEB9];
+
+/// A run of consecutive axes in the same group
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sector {
+    /// Name of the group
+    pub name: String,
+    /// Indices of the axes in the sector
+    pub axes: Range<usize>,
+    /// Position of the group among all groups, so every sector of a group
+    /// gets the same color
+    pub group_index: usize,
+}
+
+impl Sector {
+    /// Shade of the sector, the same for every sector of a group
+    pub fn color(&self, dark: bool) -> Color {
+        let shades = if dark { DARK_SHADES } else { SHADES };
+        Color::from_hex(shades[self.group_index % shades.len()])
+    }
+}
+
+/// Sectors for the optional group of each axis in `groups`. Neighboring
+/// axes of the same group form one sector, axes without a group belong to
+/// none.
+pub fn sectors(groups: &[Option<String>], axes_count: usize) -> Vec<Sector> {
+    let mut names: Vec<&String> = Vec::new();
+    let mut sectors: Vec<Sector> = Vec::new();
+    for (index, group) in groups.iter().take(axes_count).enumerate() {
+        let Some(name) = group else { continue };
+        match sectors.last_mut() {
+            Some(sector) if &sector.name == name && sector.axes.end == index => {
+                sector.axes.end = index + 1;
+            }
+            _ => {
+                let group_index = names.iter().position(|n| *n == name).unwrap_or_else(|| {
+                    names.push(name);
+                    names.len() - 1
+                });
+                sectors.push(Sector {
+                    name: name.clone(),
+                    axes: index..index + 1,
+                    group_index,
+                });
+            }
+        }
+    }
+    sectors
+}
+
+/// Axis indices a sector boundary is drawn in front of. A boundary before
+/// the first axis only exists when the axes go all the way around.
+fn boundaries(sectors: &[Sector], axes_count: usize, full_circle: bool) -> Vec<usize> {
+    // One group spanning all axes has nothing to separate
+    if sectors.len() == 1 && sectors[0].axes.len() == axes_count {
+        return Vec::new();
+    }
+    let mut boundaries = sectors
+        .iter()
+        .flat_map(|sector| [sector.axes.start, sector.axes.end])
+        .map(|index| {
+            if full_circle {
+                index % axes_count
+            } else {
+                index
+            }
+        })
+        .filter(|&index| full_circle || (index > 0 && index < axes_count))
+        .collect::<Vec<_>>();
+    boundaries.sort_unstable();
+    boundaries.dedup();
+    boundaries
+}
+
+#[derive(Props, PartialEq, Clone)]
+pub struct RadarSectorsProps {
+    /// Sectors of the axis groups, see [`sectors`]
+    pub sectors: Vec<Sector>,
+    /// Number of axes in the chart
+    pub axes_count: usize,
+    /// Position and angles of the axes, including the ring
+    pub geometry: RadarGeometry,
+}
+
+/// Component for rendering the ring of group names around the plot and the
+/// separators between sectors
+#[component]
+pub fn RadarSectors(props: RadarSectorsProps) -> Element {
+    let theme = use_theme();
+    // Names follow arcs that are referenced by id
+    let path_prefix = use_hook(|| unique_id("radar-sector"));
+    let geometry = props.geometry;
+    let (inner, outer) = (geometry.radius, geometry.ring_outer());
+    let text_distance = (inner + outer) / 2.0;
+    // Separators run halfway between two neighboring axes
+    let half_step = (geometry.angle(1) - geometry.angle(0)) / 2.0;
+
+    let separators = boundaries(&props.sectors, props.axes_count, geometry.is_full_circle())
+        .into_iter()
+        .map(|index| {
+            let (x, y) = geometry.point_at(outer, geometry.angle(index) - half_step);
+            rsx! {
+                line {
+                    key: "{index}",
+                    x1: "{geometry.center_x}",
+                    y1: "{geometry.center_y}",
+                    x2: "{x}",
+                    y2: "{y}",
+                    stroke: "{theme.axis}",
+                    "stroke-width": "1.5",
+                    "stroke-dasharray": "4 3",
+                }
+            }
+        });
+
+    let bands = props.sectors.iter().enumerate().map(|(index, sector)| {
+        let color = sector.color(theme.is_dark);
+        let (start, end) = geometry.sector_angles(&sector.axes);
+        // Text on the lower half runs backwards along the arc, so it isn't
+        // upside down
+        let upside_down = ((start + end) / 2.0).sin() > 0.0;
+        let text_arc = if upside_down {
+            geometry.arc(end, start, text_distance)
+        } else {
+            geometry.arc(start, end, text_distance)
+        };
+        let arc_length = (end - start).abs() * text_distance;
+        let name = AxisLabel::wrap(&sector.name, arc_length - 8.0, 1);
+        let path_id = format!("{path_prefix}-{index}");
+
+        rsx! {
+            g {
+                key: "{sector.axes.start}",
+                path {
+                    d: "{geometry.sector_path(&sector.axes, inner, outer)}",
+                    fill: "{color}",
+                    "fill-opacity": "0.35",
+                    stroke: "{theme.background}",
+                    "stroke-width": "1",
+                }
+                path { id: "{path_id}", d: "{text_arc}", fill: "none" }
+                text {
+                    "font-size": "11",
+                    "font-weight": "600",
+                    "dominant-baseline": "middle",
+                    fill: "{theme.text}",
+                    if name.truncated {
+                        title { "{sector.name}" }
+                    }
+                    textPath {
+                        "href": "#{path_id}",
+                        "startOffset": "50%",
+                        "text-anchor": "middle",
+                        "{name.lines[0]}"
+                    }
+                }
+            }
+        }
+    });
+
+    rsx! {
+        g {
+            class: "radar-sectors",
+            {separators}
+            {bands}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sectors_and_boundaries() {
+        let group = |name: &str| Some(name.to_string());
+        let groups = vec![
+            group("A"),
+            group("A"),
+            group("B"),
+            None,
+            group("A"),
+            group("A"),
+        ];
+        let sectors = sectors(&groups, 6);
+        let ranges = sectors.iter().map(|s| s.axes.clone()).collect::<Vec<_>>();
+        assert_eq!(ranges, vec![0..2, 2..3, 4..6]);
+        assert_eq!(sectors[2].group_index, 0);
+        assert_eq!(boundaries(&sectors, 6, true), vec![0, 2, 3, 4]);
+        assert_eq!(boundaries(&sectors, 6, false), vec![2, 3, 4]);
+    }
+}
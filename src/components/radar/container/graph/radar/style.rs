@@ -0,0 +1,179 @@
+//! Visual style of a single curve: stroke, fill and data point markers
+
+/// Dash pattern of the curve outline
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum DashPattern {
+    #[default]
+    Solid,
+    Dashed,
+    Dotted,
+    /// Custom `stroke-dasharray` lengths
+    Custom(Vec<f32>),
+}
+
+impl DashPattern {
+    /// Value for the SVG `stroke-dasharray` attribute
+    pub fn dasharray(&self) -> String {
+        match self {
+            DashPattern::Solid => "none".to_string(),
+            DashPattern::Dashed => "6 4".to_string(),
+            DashPattern::Dotted => "1 3".to_string(),
+            DashPattern::Custom(lengths) => lengths
+                .iter()
+                .map(|length| length.to_string())
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+}
+
+/// How the area enclosed by the curve is filled
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum CurveFill {
+    /// Use the fill opacity of the active theme
+    #[default]
+    Theme,
+    /// Fill with a fixed opacity from 0.0 to 1.0
+    Opacity(f32),
+    /// Outline only
+    None,
+}
+
+impl CurveFill {
+    /// Effective fill opacity given the theme default (which differs for
+    /// solid and pattern fills)
+    pub fn opacity(&self, theme_opacity: f32) -> f32 {
+        match self {
+            CurveFill::Theme => theme_opacity,
+            CurveFill::Opacity(opacity) => opacity.clamp(0.0, 1.0),
+            CurveFill::None => 0.0,
+        }
+    }
+}
+
+/// Pattern drawn inside the enclosed area instead of a solid fill
+///
+/// Patterns keep overlapping curves apart when colors are hard to tell apart,
+/// e.g. in grayscale prints or for colorblind readers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FillPattern {
+    DiagonalHatch,
+    Dots,
+    CrossHatch,
+}
+
+impl FillPattern {
+    /// All patterns in the order they are assigned automatically
+    pub const ALL: [FillPattern; 3] = [
+        FillPattern::DiagonalHatch,
+        FillPattern::Dots,
+        FillPattern::CrossHatch,
+    ];
+}
+
+/// Shape drawn at each data point
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MarkerShape {
+    #[default]
+    Circle,
+    Square,
+    Triangle,
+    Diamond,
+    None,
+}
+
+impl MarkerShape {
+    /// SVG path of the marker centered on (`x`, `y`), where `size` is the
+    /// distance from the center to the edge. `None` for [`MarkerShape::None`].
+    pub fn path(&self, x: f32, y: f32, size: f32) -> Option<String> {
+        let s = size;
+        match self {
+            MarkerShape::Circle => Some(format!(
+                "M{},{} a{s},{s} 0 1,0 {},0 a{s},{s} 0 1,0 {},0 Z",
+                x - s,
+                y,
+                2.0 * s,
+                -2.0 * s
+            )),
+            MarkerShape::Square => Some(format!(
+                "M{},{} h{} v{} h{} Z",
+                x - s,
+                y - s,
+                2.0 * s,
+                2.0 * s,
+                -2.0 * s
+            )),
+            // Slightly larger so it looks as heavy as the other shapes
+            MarkerShape::Triangle => {
+                let s = s * 1.2;
+                Some(format!(
+                    "M{},{} L{},{} L{},{} Z",
+                    x,
+                    y - s,
+                    x + s,
+                    y + s * 0.8,
+                    x - s,
+                    y + s * 0.8
+                ))
+            }
+            MarkerShape::Diamond => {
+                let s = s * 1.2;
+                Some(format!(
+                    "M{},{} L{},{} L{},{} L{},{} Z",
+                    x,
+                    y - s,
+                    x + s,
+                    y,
+                    x,
+                    y + s,
+                    x - s,
+                    y
+                ))
+            }
+            MarkerShape::None => None,
+        }
+    }
+}
+
+/// Style of a curve's outline, fill and markers
+#[derive(Clone, Debug, PartialEq)]
+pub struct CurveStyle {
+    /// Width of the outline
+    pub stroke_width: f32,
+    /// Dash pattern of the outline
+    pub dash: DashPattern,
+    /// Fill of the enclosed area
+    pub fill: CurveFill,
+    /// Optional pattern used instead of a solid fill
+    pub pattern: Option<FillPattern>,
+    /// Shape of the data point markers
+    pub marker: MarkerShape,
+    /// Marker size, measured from the center to the edge
+    pub marker_size: f32,
+}
+
+impl Default for CurveStyle {
+    fn default() -> Self {
+        Self {
+            stroke_width: 2.0,
+            dash: DashPattern::Solid,
+            fill: CurveFill::Theme,
+            pattern: None,
+            marker: MarkerShape::Circle,
+            marker_size: 4.0,
+        }
+    }
+}
+
+impl CurveStyle {
+    /// Dashed outline without fill or markers, e.g. for baselines and targets
+    pub fn baseline() -> Self {
+        Self {
+            stroke_width: 1.5,
+            dash: DashPattern::Dashed,
+            fill: CurveFill::None,
+            marker: MarkerShape::None,
+            ..Default::default()
+        }
+    }
+}
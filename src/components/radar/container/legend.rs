@@ -1,6 +1,60 @@
-use super::graph::radar::RadarCurve;
+use super::graph::radar::patterns::{pattern_id, PatternDefs};
+use super::graph::radar::style::CurveFill;
+use super::graph::radar::{CurveId, RadarCurve};
+use super::stats::{curve_stats, group_names, group_scores, StatColumn};
+use crate::components::theme::use_theme;
+use crate::components::utils::unique_id;
 use dioxus::prelude::*;
 
+/// Direction in which the legend entries flow
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum LegendLayout {
+    /// One entry per row
+    #[default]
+    Vertical,
+    /// Entries side by side, wrapping onto further lines
+    Horizontal,
+}
+
+/// Where [`RadarContainer`](super::RadarContainer) puts the legend
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum LegendPlacement {
+    /// In a card beside the chart, below it on small screens
+    #[default]
+    Right,
+    /// In a card before the chart, above it on small screens
+    Left,
+    /// Above the chart
+    Top,
+    /// Below the chart
+    Bottom,
+    /// No legend at all, e.g. together with direct labels
+    Hidden,
+    /// Inside the chart area, in the top right corner
+    Overlay,
+}
+
+impl LegendPlacement {
+    /// Layout of the legend entries that fits the placement
+    pub fn layout(self) -> LegendLayout {
+        match self {
+            Self::Top | Self::Bottom => LegendLayout::Horizontal,
+            _ => LegendLayout::Vertical,
+        }
+    }
+}
+
+/// What the legend entries are sorted by
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SortKey {
+    /// A statistics column
+    Column(StatColumn),
+    /// The score of the axis group at this position in the group names
+    Group(usize),
+    /// The values on an axis
+    Axis(usize),
+}
+
 /// Props for the RadarLegend component
 #[derive(Props, PartialEq, Clone)]
 pub struct RadarLegendProps {
@@ -8,101 +62,488 @@ pub struct RadarLegendProps {
     pub curves: Vec<RadarCurve>,
     /// Optional visibility map for curves
     #[props(optional)]
-    pub visible_map: Option<Signal<Vec<(String, bool)>>>,
-    /// Optional click handler for legend items
+    pub visible_map: Option<Signal<Vec<(CurveId, bool)>>>,
+    /// Optional click handler for legend items, receives the id of the clicked curve
     #[props(optional)]
-    pub on_click: Option<EventHandler<String>>,
-    /// Optional layout direction (horizontal or vertical)
-    #[props(default = "vertical".to_string())]
-    pub layout: String,
+    pub on_click: Option<EventHandler<CurveId>>,
+    /// Optional handler for alt-clicks, which should show only the given curve
+    #[props(optional)]
+    pub on_solo: Option<EventHandler<CurveId>>,
+    /// Optional handler for the show/hide button of a group header, receives
+    /// the group name
+    #[props(optional)]
+    pub on_group_toggle: Option<EventHandler<String>>,
+    /// Curve whose entry is emphasized because it is hovered or focused
+    #[props(optional)]
+    pub highlighted: Option<CurveId>,
+    /// Optional hover handler, receives the hovered curve or `None` on leave
+    #[props(optional)]
+    pub on_hover: Option<EventHandler<Option<CurveId>>>,
+    /// Layout direction of the entries
+    #[props(default)]
+    pub layout: LegendLayout,
+    /// Maximum height in pixels; longer legends scroll
+    #[props(optional)]
+    pub max_height: Option<u32>,
+    /// Show a box to filter the entries by curve or group name
+    #[props(default = true)]
+    pub filterable: bool,
+    /// Statistics shown as sortable columns next to the names (vertical layout only)
+    #[props(default)]
+    pub stats: Vec<StatColumn>,
+    /// Maximum value of the scale, needed for the area statistic
+    #[props(default = 100.0)]
+    pub max_value: f32,
+    /// Angle in degrees the chart's axes are spread over, needed for the area
+    /// statistic
+    #[props(default = 360.0)]
+    pub sweep: f32,
+    /// Per-axis weights for the score statistic; missing weights count as 1
+    #[props(default)]
+    pub weights: Vec<f32>,
+    /// Optional group of each axis; adds a score column per group (vertical
+    /// layout only)
+    #[props(default)]
+    pub axis_groups: Vec<Option<String>>,
+    /// Sort the entries by their value on this axis, highest first
+    #[props(optional)]
+    pub sort_axis: Option<usize>,
+    /// Optional handler for when the sort by `sort_axis` is dropped, by
+    /// clearing it or sorting by a column instead
+    #[props(optional)]
+    pub on_sort_axis_clear: Option<EventHandler<()>>,
 }
 
 /// A standalone legend component for the radar graph
 ///
 /// This component can be positioned independently of the radar graph
-/// and can be displayed in either horizontal or vertical layout.
+/// and can be displayed in either horizontal or vertical layout. Curves with
+/// a [`RadarCurve::group`] are listed under collapsible group headers after
+/// the ungrouped ones.
 #[component]
 pub fn RadarLegend(props: RadarLegendProps) -> Element {
-    // Create legend items with click handling
-    let legend_items = props.curves.iter().enumerate().map(|(index, curve)| {
-        // Check if the curve is visible
-        let is_visible = match &props.visible_map {
-            Some(vis_map) => vis_map
-                .read()
-                .iter()
-                .find(|(name, _)| name == &curve.name)
-                .map(|(_, vis)| *vis)
-                .unwrap_or(true),
-            None => true, // Default to visible if no visibility state is provided
-        };
+    let theme = use_theme();
+    // Swatches reference the patterns by id, so the legend needs its own definitions
+    let pattern_prefix = use_hook(|| unique_id("legend-pattern"));
+    let mut filter = use_signal(String::new);
+    let mut collapsed = use_signal(Vec::<String>::new);
+    // Key to sort by and whether the order is descending
+    let mut sort = use_signal(|| None::<(SortKey, bool)>);
 
-        // Set up click handler
-        let curve_name = curve.name.clone();
-        let on_click = props.on_click;
-        let onclick = move |_| {
-            if let Some(handler) = &on_click {
-                handler.call(curve_name.clone());
+    // Follow the axis the parent asks to sort by; header clicks still override it
+    let mut synced_sort_axis = use_signal(|| None::<usize>);
+    if *synced_sort_axis.peek() != props.sort_axis {
+        synced_sort_axis.set(props.sort_axis);
+        sort.set(props.sort_axis.map(|axis| (SortKey::Axis(axis), true)));
+    }
+
+    let is_visible = |curve: &RadarCurve| match &props.visible_map {
+        Some(vis_map) => vis_map
+            .read()
+            .iter()
+            .find(|(id, _)| id == &curve.id)
+            .map(|(_, vis)| *vis)
+            .unwrap_or(true),
+        None => true, // Default to visible if no visibility state is provided
+    };
+
+    // Keep the position in the full list, pattern ids are based on it
+    let query = filter.read().trim().to_lowercase();
+    let matches = |curve: &RadarCurve| {
+        query.is_empty()
+            || curve.name.to_lowercase().contains(&query)
+            || curve
+                .group
+                .as_ref()
+                .is_some_and(|group| group.to_lowercase().contains(&query))
+    };
+    let mut sections = group_sections(&props.curves, matches);
+
+    // Statistics columns turn the legend into a ranking table
+    let columns = match props.layout {
+        LegendLayout::Vertical => props.stats.clone(),
+        LegendLayout::Horizontal => Vec::new(),
+    };
+    let stats = props
+        .curves
+        .iter()
+        .map(|curve| curve_stats(curve, props.max_value, &props.weights, props.sweep))
+        .collect::<Vec<_>>();
+    let groups = match props.layout {
+        LegendLayout::Vertical => group_names(&props.axis_groups),
+        LegendLayout::Horizontal => Vec::new(),
+    };
+    let scores = props
+        .curves
+        .iter()
+        .map(|curve| group_scores(curve, &props.axis_groups, &groups, &props.weights))
+        .collect::<Vec<_>>();
+    let sort_value = |key: SortKey, index: usize| match key {
+        SortKey::Column(column) => column.value(&stats[index]),
+        SortKey::Group(group) => scores[index].get(group).copied().unwrap_or_default(),
+        SortKey::Axis(axis) => props.curves[index]
+            .data_points
+            .get(axis)
+            .map_or(f32::NEG_INFINITY, |point| point.value),
+    };
+    if let Some((key, descending)) = *sort.read() {
+        for (_, indices) in sections.iter_mut() {
+            indices.sort_by(|&a, &b| {
+                let order = sort_value(key, a).total_cmp(&sort_value(key, b));
+                if descending {
+                    order.reverse()
+                } else {
+                    order
+                }
+            });
+        }
+    }
+    let grid_style = format!(
+        "grid-template-columns: minmax(0, 1fr){};",
+        " 3.5rem".repeat(columns.len() + groups.len())
+    );
+
+    let row = |index: usize| {
+        let curve = &props.curves[index];
+        rsx! {
+            LegendRow {
+                key: "{curve.id}",
+                curve: curve.clone(),
+                pattern_fill: format!("url(#{})", pattern_id(&pattern_prefix, index)),
+                visible: is_visible(curve),
+                highlighted: props.highlighted.as_ref() == Some(&curve.id),
+                cells: columns
+                    .iter()
+                    .map(|column| column.format(&stats[index]))
+                    .chain(scores[index].iter().map(|score| format!("{score:.1}")))
+                    .collect::<Vec<_>>(),
+                grid_style: grid_style.clone(),
+                curve_fill_opacity: theme.curve_fill_opacity,
+                curve_pattern_opacity: theme.curve_pattern_opacity,
+                on_click: props.on_click,
+                on_solo: props.on_solo,
+                on_hover: props.on_hover,
             }
-        };
+        }
+    };
 
-        // Determine position based on layout
-        let transform = if props.layout == "horizontal" {
-            format!("translate({}, 0)", index as u32 * 120)
-        } else {
-            format!("translate(0, {})", index as u32 * 20)
-        };
+    let list_class = match props.layout {
+        LegendLayout::Horizontal => "flex flex-row flex-wrap gap-x-4 gap-y-1",
+        LegendLayout::Vertical => "flex flex-col gap-1",
+    };
 
-        // Determine text classes and styles based on visibility
-        let (text_classes, text_style) = if is_visible {
-            ("text-xs cursor-pointer", "fill: currentColor;")
-        } else {
-            (
-                "text-xs cursor-pointer line-through opacity-70",
-                "fill: currentColor;",
-            )
+    let section_items = sections.into_iter().map(|(group, indices)| {
+        let rows = indices.iter().map(|&index| row(index));
+        let Some(group) = group else {
+            return rsx! {
+                div { key: "ungrouped", class: "{list_class}", {rows} }
+            };
         };
 
-        // Determine rectangle classes based on visibility
-        let rect_classes = if is_visible {
-            "cursor-pointer"
-        } else {
-            "cursor-pointer opacity-30"
-        };
+        // Matches inside collapsed groups stay reachable while filtering
+        let is_collapsed = query.is_empty() && collapsed.read().contains(&group);
+        let visible_count = indices
+            .iter()
+            .filter(|&&index| is_visible(&props.curves[index]))
+            .count();
+        let toggle_label = if visible_count > 0 { "Hide" } else { "Show" };
+        let collapse_group = group.clone();
+        let toggle_group = group.clone();
+        let on_group_toggle = props.on_group_toggle;
 
         rsx! {
-            g {
-                transform: "{transform}",
-                onclick: onclick,
-                rect {
-                    width: "15",
-                    height: "15",
-                    fill: "{curve.color}",
-                    class: "{rect_classes}"
+            div {
+                key: "group-{group}",
+                class: "flex flex-col gap-1",
+                div {
+                    class: "flex items-center gap-2 text-xs font-semibold",
+                    button {
+                        class: "flex-1 text-left break-words",
+                        title: if is_collapsed { "Expand group" } else { "Collapse group" },
+                        onclick: move |_| {
+                            let mut groups = collapsed.write();
+                            match groups.iter().position(|g| g == &collapse_group) {
+                                Some(position) => {
+                                    groups.remove(position);
+                                }
+                                None => groups.push(collapse_group.clone()),
+                            }
+                        },
+                        if is_collapsed { "▸ " } else { "▾ " }
+                        "{group} ({visible_count}/{indices.len()})"
+                    }
+                    if on_group_toggle.is_some() {
+                        button {
+                            class: "px-2 rounded border font-normal",
+                            onclick: move |_| {
+                                if let Some(handler) = &on_group_toggle {
+                                    handler.call(toggle_group.clone());
+                                }
+                            },
+                            "{toggle_label}"
+                        }
+                    }
                 }
-                text {
-                    x: "20",
-                    y: "12",
-                    class: "{text_classes}",
-                    style: "{text_style}",
-                    "{curve.name}"
+                if !is_collapsed {
+                    div { class: "{list_class} pl-3", {rows} }
                 }
             }
         }
     });
 
+    let scroll_style = match props.max_height {
+        Some(height) => format!("max-height: {height}px; overflow-y: auto;"),
+        None => String::new(),
+    };
+    let is_empty = props.curves.iter().all(|curve| !matches(curve));
+
+    // Clicking a header sorts descending, then ascending, then unsorted
+    let on_sort_axis_clear = props.on_sort_axis_clear;
+    let headers = columns
+        .iter()
+        .map(|&column| (SortKey::Column(column), column.label().to_string()))
+        .chain(
+            groups
+                .iter()
+                .enumerate()
+                .map(|(index, name)| (SortKey::Group(index), name.clone())),
+        );
+    let header_cells = headers.map(|(key, label)| {
+        let current = *sort.read();
+        let indicator = match current {
+            Some((sorted, true)) if sorted == key => " ▼",
+            Some((sorted, false)) if sorted == key => " ▲",
+            _ => "",
+        };
+        rsx! {
+            button {
+                key: "{key:?}",
+                class: "text-right font-semibold truncate",
+                title: "Sort by {label}",
+                onclick: move |_| {
+                    let next = match current {
+                        Some((sorted, true)) if sorted == key => Some((key, false)),
+                        Some((sorted, false)) if sorted == key => None,
+                        _ => Some((key, true)),
+                    };
+                    if let (Some((SortKey::Axis(_), _)), Some(handler)) = (current, &on_sort_axis_clear) {
+                        // The parent is about to drop the axis, which must not
+                        // reset the new sort
+                        synced_sort_axis.set(None);
+                        handler.call(());
+                    }
+                    sort.set(next);
+                },
+                "{label}{indicator}"
+            }
+        }
+    });
+
+    // Axis labels are only known through the data points
+    let sorted_axis = match *sort.read() {
+        Some((SortKey::Axis(axis), _)) => props
+            .curves
+            .iter()
+            .find_map(|curve| curve.data_points.get(axis))
+            .map(|point| point.label.clone()),
+        _ => None,
+    };
+
     rsx! {
         div {
-            class: "legend-container text-gray-800 dark:text-white",
+            class: "legend-container flex flex-col gap-2",
+            style: "color: {theme.text};",
+            // Zero-sized SVG holding the pattern fills used by the swatches
             svg {
-                // Adjust width based on layout
-                width: if props.layout == "horizontal" { "100%" } else { "150px" },
-                // Adjust height based on layout and number of items
-                height: if props.layout == "horizontal" { "30px" } else { format!("{}px", props.curves.len() * 20 + 10) },
-                g {
-                    class: "legend",
-                    {legend_items}
+                width: "0",
+                height: "0",
+                style: "position: absolute;",
+                PatternDefs {
+                    prefix: pattern_prefix.clone(),
+                    curves: props.curves.clone(),
+                }
+            }
+            if props.filterable {
+                input {
+                    r#type: "search",
+                    class: "text-xs px-2 py-1 rounded border bg-transparent",
+                    placeholder: "Filter curves",
+                    value: "{filter}",
+                    oninput: move |evt| filter.set(evt.value()),
+                }
+            }
+            if let Some(axis) = sorted_axis {
+                div {
+                    class: "flex items-center gap-2 text-xs opacity-70",
+                    span { "Sorted by {axis}" }
+                    button {
+                        title: "Clear sorting",
+                        onclick: move |_| {
+                            sort.set(None);
+                            if let Some(handler) = &on_sort_axis_clear {
+                                synced_sort_axis.set(None);
+                                handler.call(());
+                            }
+                        },
+                        "×"
+                    }
+                }
+            }
+            if !columns.is_empty() || !groups.is_empty() {
+                div {
+                    class: "grid gap-2 text-xs",
+                    style: "{grid_style}",
+                    span { class: "font-semibold", "Curve" }
+                    {header_cells}
+                }
+            }
+            div {
+                class: "legend flex flex-col gap-2",
+                style: "{scroll_style}",
+                {section_items}
+                if is_empty {
+                    span { class: "text-xs opacity-70", "No matching curves" }
+                }
+            }
+        }
+    }
+}
+
+/// A single legend entry: swatch and wrapping curve name
+#[component]
+fn LegendRow(
+    curve: RadarCurve,
+    pattern_fill: String,
+    visible: bool,
+    highlighted: bool,
+    cells: Vec<String>,
+    grid_style: String,
+    curve_fill_opacity: f32,
+    curve_pattern_opacity: f32,
+    on_click: Option<EventHandler<CurveId>>,
+    on_solo: Option<EventHandler<CurveId>>,
+    on_hover: Option<EventHandler<Option<CurveId>>>,
+) -> Element {
+    // The swatch mirrors the curve style: filled area, outline and marker,
+    // scaled down so heavy styles still fit the 15px box
+    let color = curve.color.unwrap_or_default();
+    let style = &curve.style;
+    let (swatch_fill, swatch_fill_opacity) = match (style.fill, style.pattern) {
+        (CurveFill::None, _) => ("none".to_string(), 0.0),
+        (fill, Some(_)) => (pattern_fill, fill.opacity(curve_pattern_opacity)),
+        (fill, None) => (color.to_string(), fill.opacity(curve_fill_opacity)),
+    };
+    let swatch_stroke_width = style.stroke_width.min(3.0);
+    let swatch_dasharray = style.dash.dasharray();
+    let swatch_marker = style.marker.path(7.5, 7.5, style.marker_size.min(4.0));
+
+    let click_id = curve.id.clone();
+    let hover_id = curve.id.clone();
+
+    // Determine text and swatch classes based on visibility and emphasis
+    let text_classes = match (visible, highlighted) {
+        (true, true) => "text-xs break-words min-w-0 font-bold",
+        (true, false) => "text-xs break-words min-w-0",
+        (false, _) => "text-xs break-words min-w-0 line-through opacity-70",
+    };
+    let swatch_classes = if visible {
+        "flex-shrink-0"
+    } else {
+        "flex-shrink-0 opacity-30"
+    };
+
+    rsx! {
+        div {
+            class: "grid items-start gap-2 cursor-pointer select-none",
+            style: "{grid_style}",
+            onclick: move |evt: MouseEvent| {
+                let handler = if evt.modifiers().alt() { on_solo } else { on_click };
+                if let Some(handler) = &handler {
+                    handler.call(click_id.clone());
+                }
+            },
+            onmouseenter: move |_| {
+                if let Some(handler) = &on_hover {
+                    handler.call(Some(hover_id.clone()));
+                }
+            },
+            onmouseleave: move |_| {
+                if let Some(handler) = &on_hover {
+                    handler.call(None);
+                }
+            },
+            div {
+                class: "flex items-start gap-2 min-w-0",
+                svg {
+                    class: "{swatch_classes}",
+                    width: "15",
+                    height: "15",
+                    rect {
+                        width: "15",
+                        height: "15",
+                        fill: "{swatch_fill}",
+                        "fill-opacity": "{swatch_fill_opacity}",
+                    }
+                    line {
+                        x1: "0",
+                        y1: "7.5",
+                        x2: "15",
+                        y2: "7.5",
+                        stroke: "{color}",
+                        "stroke-width": "{swatch_stroke_width}",
+                        "stroke-dasharray": "{swatch_dasharray}",
+                    }
+                    if let Some(marker) = swatch_marker {
+                        path {
+                            d: "{marker}",
+                            fill: "{color}",
+                            stroke: "{color.darken(0.3)}",
+                            "stroke-width": "1",
+                        }
+                    }
                 }
+                span { class: "{text_classes}", "{curve.name}" }
             }
+            for cell in cells {
+                span { class: "text-xs text-right tabular-nums", "{cell}" }
+            }
+        }
+    }
+}
+
+/// Split the curves matching `matches` into sections of curve indices:
+/// ungrouped curves first, then one section per group in order of appearance
+fn group_sections(
+    curves: &[RadarCurve],
+    matches: impl Fn(&RadarCurve) -> bool,
+) -> Vec<(Option<String>, Vec<usize>)> {
+    let mut sections: Vec<(Option<String>, Vec<usize>)> = vec![(None, Vec::new())];
+    for (index, curve) in curves.iter().enumerate().filter(|(_, c)| matches(c)) {
+        match sections.iter_mut().find(|(group, _)| group == &curve.group) {
+            Some((_, indices)) => indices.push(index),
+            None => sections.push((curve.group.clone(), vec![index])),
         }
     }
+    sections.retain(|(_, indices)| !indices.is_empty());
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_sections() {
+        let curves = vec![
+            RadarCurve::new("a", Vec::new()).with_group("x"),
+            RadarCurve::new("b", Vec::new()),
+            RadarCurve::new("c", Vec::new()).with_group("y"),
+            RadarCurve::new("d", Vec::new()).with_group("x"),
+        ];
+        let sections = group_sections(&curves, |curve| curve.name != "c");
+        assert_eq!(
+            sections,
+            vec![(None, vec![1]), (Some("x".to_string()), vec![0, 3])]
+        );
+    }
 }
@@ -0,0 +1,220 @@
+//! Summary statistics of curves, shown as columns in the legend
+
+use super::graph::radar::RadarCurve;
+
+/// Summary statistics of a single curve
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CurveStats {
+    /// Average of the values
+    pub mean: f32,
+    /// Sum of the values
+    pub sum: f32,
+    /// Area of the polygon through the points as a fraction of the full chart
+    pub area_fraction: f32,
+    /// Weighted average of the values, see [`curve_stats`]
+    pub score: f32,
+}
+
+/// Compute the statistics of a curve whose axes are spread over `sweep`
+/// degrees.
+///
+/// The area uses straight edges between the points and values clamped to
+/// the scale, so it measures the same shape for every curve regardless of
+/// smoothing. A partial sweep doesn't wrap from the last axis to the first,
+/// its area is compared to the wedge the chart spans. `weights` are per
+/// axis; missing weights count as 1, so without weights the score equals
+/// the mean.
+pub fn curve_stats(curve: &RadarCurve, max_value: f32, weights: &[f32], sweep: f32) -> CurveStats {
+    let values = curve
+        .data_points
+        .iter()
+        .map(|point| point.value)
+        .collect::<Vec<_>>();
+    if values.is_empty() {
+        return CurveStats::default();
+    }
+    let count = values.len() as f32;
+    let sum = values.iter().sum::<f32>();
+
+    // The triangle between two neighboring axes has the area
+    // r1 * r2 * sin(step) / 2, compared to the same triangles with every
+    // radius at 1
+    let radii = values
+        .iter()
+        .map(|value| (value / max_value).clamp(0.0, 1.0))
+        .collect::<Vec<_>>();
+    let sweep = sweep.clamp(1.0, 360.0);
+    let edges = if sweep >= 360.0 {
+        radii.len()
+    } else {
+        radii.len() - 1
+    };
+    let triangle = (sweep.to_radians() / edges.max(1) as f32).sin() / 2.0;
+    let area_fraction = if radii.len() < 3 || triangle <= 0.0 {
+        0.0
+    } else {
+        let area = (0..edges)
+            .map(|i| radii[i] * radii[(i + 1) % radii.len()] * triangle)
+            .sum::<f32>();
+        area / (edges as f32 * triangle)
+    };
+
+    let weight = |index: usize| weights.get(index).copied().unwrap_or(1.0).max(0.0);
+    let total_weight = (0..values.len()).map(weight).sum::<f32>();
+    let score = if total_weight > 0.0 {
+        values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| value * weight(index))
+            .sum::<f32>()
+            / total_weight
+    } else {
+        0.0
+    };
+
+    CurveStats {
+        mean: sum / count,
+        sum,
+        area_fraction,
+        score,
+    }
+}
+
+/// Names of the axis groups in the order their first axis appears;
+/// `groups` holds the optional group of each axis
+pub fn group_names(groups: &[Option<String>]) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for name in groups.iter().flatten() {
+        if !names.contains(name) {
+            names.push(name.clone());
+        }
+    }
+    names
+}
+
+/// Weighted average of the values of `curve` on the axes of each group in
+/// `names`, weighted like [`CurveStats::score`]
+pub fn group_scores(
+    curve: &RadarCurve,
+    groups: &[Option<String>],
+    names: &[String],
+    weights: &[f32],
+) -> Vec<f32> {
+    let weight = |index: usize| weights.get(index).copied().unwrap_or(1.0).max(0.0);
+    names
+        .iter()
+        .map(|name| {
+            let (total, total_weight) = curve
+                .data_points
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| groups.get(*index).and_then(Option::as_ref) == Some(name))
+                .fold((0.0, 0.0), |(total, total_weight), (index, point)| {
+                    (
+                        total + point.value * weight(index),
+                        total_weight + weight(index),
+                    )
+                });
+            if total_weight > 0.0 {
+                total / total_weight
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+/// A statistic that can be shown as a legend column
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StatColumn {
+    Mean,
+    Sum,
+    Area,
+    Score,
+}
+
+impl StatColumn {
+    /// Column header
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Mean => "Mean",
+            Self::Sum => "Sum",
+            Self::Area => "Area",
+            Self::Score => "Score",
+        }
+    }
+
+    /// The value of this column
+    pub fn value(self, stats: &CurveStats) -> f32 {
+        match self {
+            Self::Mean => stats.mean,
+            Self::Sum => stats.sum,
+            Self::Area => stats.area_fraction,
+            Self::Score => stats.score,
+        }
+    }
+
+    /// The value of this column formatted for display
+    pub fn format(self, stats: &CurveStats) -> String {
+        let value = self.value(stats);
+        match self {
+            Self::Area => format!("{:.0}%", value * 100.0),
+            Self::Sum => format!("{value:.0}"),
+            Self::Mean | Self::Score => format!("{value:.1}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::radar::DataPoint;
+
+    fn curve(values: &[f32]) -> RadarCurve {
+        let points = values
+            .iter()
+            .map(|value| DataPoint::new("", *value))
+            .collect();
+        RadarCurve::new("A", points)
+    }
+
+    #[test]
+    fn test_area_fraction() {
+        let full = curve_stats(&curve(&[100.0, 100.0, 100.0, 100.0]), 100.0, &[], 360.0);
+        assert_eq!(full.area_fraction, 1.0);
+        let half = curve_stats(&curve(&[50.0, 50.0, 50.0, 50.0]), 100.0, &[], 360.0);
+        assert_eq!(half.area_fraction, 0.25);
+    }
+
+    #[test]
+    fn test_area_fraction_of_semicircle() {
+        let area = |values: &[f32]| curve_stats(&curve(values), 100.0, &[], 180.0).area_fraction;
+        assert!((area(&[100.0, 100.0, 100.0]) - 1.0).abs() < 1e-6);
+        // Only the wedge between the first two axes is filled; the last and
+        // first axis are not neighbors
+        assert!((area(&[100.0, 100.0, 0.0]) - 0.5).abs() < 1e-6);
+        assert_eq!(area(&[0.0, 100.0, 100.0]), area(&[100.0, 100.0, 0.0]));
+    }
+
+    #[test]
+    fn test_weighted_score() {
+        let stats = curve_stats(&curve(&[10.0, 20.0, 60.0]), 100.0, &[2.0, 1.0], 360.0);
+        assert_eq!(stats.mean, 30.0);
+        assert_eq!(stats.sum, 90.0);
+        assert_eq!(stats.score, 25.0);
+    }
+
+    #[test]
+    fn test_group_scores() {
+        let groups = vec![
+            Some("Technical".to_string()),
+            None,
+            Some("Communication".to_string()),
+            Some("Technical".to_string()),
+        ];
+        let names = group_names(&groups);
+        assert_eq!(names, vec!["Technical", "Communication"]);
+        let scores = group_scores(&curve(&[10.0, 99.0, 40.0, 30.0]), &groups, &names, &[]);
+        assert_eq!(scores, vec![20.0, 40.0]);
+    }
+}
@@ -0,0 +1,206 @@
+use super::graph::radar::RadarCurve;
+use super::RadarError;
+
+/// Result of validating a complete radar dataset
+///
+/// Errors make the dataset unusable for rendering, warnings describe data that
+/// renders but probably not the way the caller intended (e.g. clamped values).
+#[derive(Debug, Clone, Default)]
+pub struct ValidationReport {
+    /// Problems that prevent the dataset from being displayed correctly
+    pub errors: Vec<RadarError>,
+    /// Non-fatal problems that are worth surfacing to the user
+    pub warnings: Vec<RadarError>,
+}
+
+impl ValidationReport {
+    /// Returns true if no errors were found (warnings are allowed)
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Converts the report into a `Result`, returning the first error if any
+    pub fn into_result(mut self) -> Result<Vec<RadarError>, RadarError> {
+        if self.is_valid() {
+            Ok(self.warnings)
+        } else {
+            Err(self.errors.swap_remove(0))
+        }
+    }
+}
+
+/// Validates axes and curves as a whole and collects every problem found
+///
+/// Unlike the early-return checks this function does not stop at the first
+/// problem, so callers can show all issues of a dataset at once.
+pub fn validate_data(axes: &[String], curves: &[RadarCurve], max_value: f32) -> ValidationReport {
+    let mut report = ValidationReport::default();
+
+    if axes.is_empty() {
+        report.errors.push(RadarError::NoAxesProvided);
+    }
+
+    if curves.is_empty() {
+        report.errors.push(RadarError::NoCurvesProvided);
+    }
+
+    if !max_value.is_finite() || max_value <= 0.0 {
+        report
+            .errors
+            .push(RadarError::InvalidMaxValue { value: max_value });
+    }
+
+    // Duplicate axis names make labels ambiguous
+    for (index, axis) in axes.iter().enumerate() {
+        if is_first_duplicate(axes, index, |a| a) {
+            report.errors.push(RadarError::DuplicateAxisName {
+                axis_name: axis.clone(),
+            });
+        }
+    }
+
+    for (curve_index, curve) in curves.iter().enumerate() {
+        // Duplicate curve ids break id-keyed state such as visibility
+        if is_first_duplicate(curves, curve_index, |c| &c.id.0) {
+            report.errors.push(RadarError::DuplicateCurveId {
+                curve_id: curve.id.clone(),
+            });
+        }
+
+        // Duplicate names still render, but are indistinguishable in the legend
+        if is_first_duplicate(curves, curve_index, |c| &c.name) {
+            report.warnings.push(RadarError::DuplicateCurveName {
+                curve_name: curve.name.clone(),
+            });
+        }
+
+        if curve.data_points.len() != axes.len() {
+            report.errors.push(RadarError::DataPointCountMismatch {
+                curve_name: curve.name.clone(),
+                expected: axes.len(),
+                actual: curve.data_points.len(),
+            });
+        }
+
+        for (point_index, data_point) in curve.data_points.iter().enumerate() {
+            if !data_point.value.is_finite() {
+                report.errors.push(RadarError::NonFiniteValue {
+                    curve_name: curve.name.clone(),
+                    point_index,
+                    value: data_point.value,
+                });
+            } else if max_value.is_finite()
+                && (data_point.value < 0.0 || data_point.value > max_value)
+            {
+                report.warnings.push(RadarError::ValueOutOfScale {
+                    curve_name: curve.name.clone(),
+                    point_index,
+                    value: data_point.value,
+                    max_value,
+                });
+            }
+
+            if let Some(axis) = axes.get(point_index) {
+                if &data_point.label != axis {
+                    report.errors.push(RadarError::LabelMismatch {
+                        curve_name: curve.name.clone(),
+                        point_index,
+                        expected: axis.clone(),
+                        actual: data_point.label.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    report
+}
+
+/// Returns true if the item at `index` is the second occurrence of its key,
+/// so every duplicated key is reported exactly once
+fn is_first_duplicate<T>(items: &[T], index: usize, key: impl Fn(&T) -> &String) -> bool {
+    let current = key(&items[index]);
+    items[..index]
+        .iter()
+        .filter(|item| key(item) == current)
+        .count()
+        == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::radar::DataPoint;
+
+    fn axes() -> Vec<String> {
+        vec!["Speed".to_string(), "Power".to_string()]
+    }
+
+    fn curve(name: &str, values: &[(&str, f32)]) -> RadarCurve {
+        RadarCurve::new(
+            name,
+            values
+                .iter()
+                .map(|(label, value)| DataPoint::new(*label, *value))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_valid_dataset() {
+        let curves = vec![curve("A", &[("Speed", 10.0), ("Power", 20.0)])];
+        let report = validate_data(&axes(), &curves, 100.0);
+        assert!(report.is_valid());
+        assert!(report.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_collects_all_errors() {
+        let curves = vec![
+            curve("A", &[("Speed", f32::NAN), ("Power", 20.0)]),
+            curve("A", &[("Power", 10.0), ("Speed", 20.0)]),
+            curve("B", &[("Speed", 10.0)]),
+        ];
+        let axes = vec![
+            "Speed".to_string(),
+            "Power".to_string(),
+            "Power".to_string(),
+        ];
+        let report = validate_data(&axes, &curves, 100.0);
+
+        let has = |pred: fn(&RadarError) -> bool| report.errors.iter().filter(|e| pred(e)).count();
+        assert_eq!(
+            has(|e| matches!(e, RadarError::DuplicateAxisName { .. })),
+            1
+        );
+        assert_eq!(has(|e| matches!(e, RadarError::DuplicateCurveId { .. })), 1);
+        assert_eq!(has(|e| matches!(e, RadarError::NonFiniteValue { .. })), 1);
+        assert_eq!(has(|e| matches!(e, RadarError::LabelMismatch { .. })), 2);
+        assert_eq!(
+            has(|e| matches!(e, RadarError::DataPointCountMismatch { .. })),
+            3
+        );
+    }
+
+    #[test]
+    fn test_out_of_scale_is_warning() {
+        let curves = vec![curve("A", &[("Speed", 120.0), ("Power", -1.0)])];
+        let report = validate_data(&axes(), &curves, 100.0);
+        assert!(report.is_valid());
+        assert_eq!(report.warnings.len(), 2);
+    }
+
+    #[test]
+    fn test_duplicate_name_with_distinct_ids_is_warning() {
+        let curves = vec![
+            curve("A", &[("Speed", 10.0), ("Power", 20.0)]).with_id("a-1"),
+            curve("A", &[("Speed", 10.0), ("Power", 20.0)]).with_id("a-2"),
+        ];
+        let report = validate_data(&axes(), &curves, 100.0);
+        assert!(report.is_valid());
+        assert!(matches!(
+            report.warnings.as_slice(),
+            [RadarError::DuplicateCurveName { .. }]
+        ));
+    }
+}
@@ -0,0 +1,145 @@
+//! Operations on the visibility map shared by the graph and the legend
+//!
+//! The map is a list of `(curve id, visible)` pairs in curve order. Every bulk
+//! operation returns a new map, so the container can report it to the parent
+//! before (or instead of) applying it.
+
+use super::graph::radar::{CurveId, RadarCurve};
+
+/// A named, saved visibility map
+#[derive(Clone, Debug, PartialEq)]
+pub struct VisibilityPreset {
+    /// Name shown on the preset button
+    pub name: String,
+    /// Visibility of the curves by id; curves that are missing are visible
+    pub visibility: Vec<(CurveId, bool)>,
+}
+
+impl VisibilityPreset {
+    /// Create a preset from a visibility map
+    pub fn new(name: impl Into<String>, visibility: Vec<(CurveId, bool)>) -> Self {
+        Self {
+            name: name.into(),
+            visibility,
+        }
+    }
+}
+
+/// Name for a new preset: "View N" with the lowest number not taken yet
+pub fn preset_name(presets: &[VisibilityPreset]) -> String {
+    (1..)
+        .map(|number| format!("View {number}"))
+        .find(|name| presets.iter().all(|preset| &preset.name != name))
+        .unwrap_or_default()
+}
+
+/// Build a visibility map for `curves`, taking the state from `existing`
+/// where present and defaulting new curves to visible
+pub fn merge_visibility(
+    curves: &[RadarCurve],
+    existing: &[(CurveId, bool)],
+) -> Vec<(CurveId, bool)> {
+    curves
+        .iter()
+        .map(|curve| {
+            let is_visible = existing
+                .iter()
+                .find(|(id, _)| id == &curve.id)
+                .map(|(_, vis)| *vis)
+                .unwrap_or(true);
+            (curve.id.clone(), is_visible)
+        })
+        .collect()
+}
+
+/// Flip the visibility of a single curve
+pub fn toggle(map: &[(CurveId, bool)], curve_id: &CurveId) -> Vec<(CurveId, bool)> {
+    map.iter()
+        .map(|(id, visible)| (id.clone(), if id == curve_id { !visible } else { *visible }))
+        .collect()
+}
+
+/// Hide all of `curve_ids` if any of them is visible, otherwise show them all
+pub fn toggle_many(map: &[(CurveId, bool)], curve_ids: &[CurveId]) -> Vec<(CurveId, bool)> {
+    let any_visible = map
+        .iter()
+        .any(|(id, visible)| *visible && curve_ids.contains(id));
+    map.iter()
+        .map(|(id, visible)| {
+            let visible = if curve_ids.contains(id) {
+                !any_visible
+            } else {
+                *visible
+            };
+            (id.clone(), visible)
+        })
+        .collect()
+}
+
+/// Show only `curve_id`; soloing the curve that is already the only visible
+/// one shows all curves again
+pub fn solo(map: &[(CurveId, bool)], curve_id: &CurveId) -> Vec<(CurveId, bool)> {
+    let already_solo = map.iter().all(|(id, visible)| *visible == (id == curve_id));
+    if already_solo {
+        return set_all(map, true);
+    }
+    map.iter()
+        .map(|(id, _)| (id.clone(), id == curve_id))
+        .collect()
+}
+
+/// Show or hide every curve
+pub fn set_all(map: &[(CurveId, bool)], visible: bool) -> Vec<(CurveId, bool)> {
+    map.iter().map(|(id, _)| (id.clone(), visible)).collect()
+}
+
+/// Curves whose visibility differs between `old` and `new`, with their new state
+pub fn changes(old: &[(CurveId, bool)], new: &[(CurveId, bool)]) -> Vec<(CurveId, bool)> {
+    new.iter()
+        .filter(|(id, visible)| {
+            old.iter()
+                .find(|(old_id, _)| old_id == id)
+                .is_none_or(|(_, was_visible)| was_visible != visible)
+        })
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map(states: &[(&str, bool)]) -> Vec<(CurveId, bool)> {
+        states
+            .iter()
+            .map(|(id, visible)| (CurveId::from(*id), *visible))
+            .collect()
+    }
+
+    #[test]
+    fn test_solo_and_unsolo() {
+        let all = map(&[("a", true), ("b", true), ("c", false)]);
+        let soloed = solo(&all, &CurveId::from("b"));
+        assert_eq!(soloed, map(&[("a", false), ("b", true), ("c", false)]));
+        assert_eq!(solo(&soloed, &CurveId::from("b")), set_all(&all, true));
+    }
+
+    #[test]
+    fn test_preset_name_reuses_free_numbers() {
+        let presets = vec![
+            VisibilityPreset::new("View 1", Vec::new()),
+            VisibilityPreset::new("View 3", Vec::new()),
+        ];
+        assert_eq!(preset_name(&presets), "View 2");
+        assert_eq!(preset_name(&presets[..1]), "View 2");
+        assert_eq!(preset_name(&[]), "View 1");
+    }
+
+    #[test]
+    fn test_changes() {
+        let old = map(&[("a", true), ("b", true)]);
+        let new = toggle(&old, &CurveId::from("a"));
+        assert_eq!(changes(&old, &new), map(&[("a", false)]));
+        assert!(changes(&new, &new).is_empty());
+    }
+}
@@ -0,0 +1,240 @@
+//! Typed theme system shared by all components
+//!
+//! The active [`Theme`] is provided through a `Signal<ThemeState>` context by
+//! [`use_theme_provider`] and read by components via [`use_theme`]. Persisting
+//! the user's choice and detecting the system preference is platform specific:
+//! the `web` module uses `localStorage` and `matchMedia`, the `native` module
+//! (desktop and mobile) a file in the app's config directory and the window's
+//! appearance.
+
+use crate::components::color::Color;
+use dioxus::prelude::*;
+
+#[cfg(feature = "web")]
+mod web;
+#[cfg(feature = "web")]
+use web as platform;
+
+#[cfg(not(feature = "web"))]
+mod native;
+#[cfg(not(feature = "web"))]
+use native as platform;
+
+/// Colors and opacities used to draw the components
+#[derive(Clone, Debug, PartialEq)]
+pub struct Theme {
+    /// Whether this is a dark theme (toggles Tailwind's `dark` class)
+    pub is_dark: bool,
+    /// Page background
+    pub background: Color,
+    /// Background of cards such as the legend
+    pub surface: Color,
+    /// Default text color
+    pub text: Color,
+    /// Axis lines
+    pub axis: Color,
+    /// Axis labels
+    pub axis_label: Color,
+    /// Grid circles
+    pub grid: Color,
+    /// Grid scale labels
+    pub grid_label: Color,
+    /// Tooltip background
+    pub tooltip_background: Color,
+    /// Tooltip text
+    pub tooltip_text: Color,
+    /// Opacity of the tooltip background
+    pub tooltip_opacity: f32,
+    /// Fill opacity of curve polygons
+    pub curve_fill_opacity: f32,
+    /// Fill opacity of curve polygons filled with a pattern
+    pub curve_pattern_opacity: f32,
+}
+
+impl Theme {
+    /// The default light theme
+    pub fn light() -> Self {
+        Self {
+            is_dark: false,
+            background: Color::from_hex(0xFFFFFF),
+            surface: Color::from_hex(0xFFFFFF),
+            text: Color::from_hex(0x333333),
+            axis: Color::from_hex(0x888888),
+            axis_label: Color::from_hex(0x333333),
+            grid: Color::from_hex(0xDDDDDD),
+            grid_label: Color::from_hex(0x4B5563),
+            tooltip_background: Color::from_hex(0x333333),
+            tooltip_text: Color::from_hex(0xFFFFFF),
+            tooltip_opacity: 0.8,
+            curve_fill_opacity: 0.3,
+            curve_pattern_opacity: 0.7,
+        }
+    }
+
+    /// The default dark theme, matching Tailwind's gray scale
+    pub fn dark() -> Self {
+        Self {
+            is_dark: true,
+            background: Color::from_hex(0x111827),
+            surface: Color::from_hex(0x1F2937),
+            text: Color::from_hex(0xFFFFFF),
+            axis: Color::from_hex(0x6B7280),
+            axis_label: Color::from_hex(0xE5E7EB),
+            grid: Color::from_hex(0x4B5563),
+            grid_label: Color::from_hex(0xD1D5DB),
+            tooltip_background: Color::from_hex(0x1F2937),
+            tooltip_text: Color::from_hex(0xFFFFFF),
+            tooltip_opacity: 0.9,
+            curve_fill_opacity: 0.3,
+            curve_pattern_opacity: 0.8,
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+/// Which theme the user asked for
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ThemeMode {
+    Light,
+    Dark,
+    /// Follow the operating system preference
+    #[default]
+    System,
+}
+
+impl ThemeMode {
+    /// Value used when persisting the mode
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThemeMode::Light => "light",
+            ThemeMode::Dark => "dark",
+            ThemeMode::System => "system",
+        }
+    }
+
+    /// Parse a persisted mode, returning `None` for unknown values
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "light" => Some(ThemeMode::Light),
+            "dark" => Some(ThemeMode::Dark),
+            "system" => Some(ThemeMode::System),
+            _ => None,
+        }
+    }
+}
+
+/// Theme state shared through the context
+///
+/// `light` and `dark` can be replaced with custom themes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThemeState {
+    /// The mode chosen by the user
+    pub mode: ThemeMode,
+    /// Whether the operating system currently prefers a dark theme
+    pub system_dark: bool,
+    /// Theme used in light mode
+    pub light: Theme,
+    /// Theme used in dark mode
+    pub dark: Theme,
+}
+
+impl Default for ThemeState {
+    fn default() -> Self {
+        Self {
+            mode: ThemeMode::default(),
+            system_dark: false,
+            light: Theme::light(),
+            dark: Theme::dark(),
+        }
+    }
+}
+
+impl ThemeState {
+    /// Whether the dark theme is in effect
+    pub fn is_dark(&self) -> bool {
+        match self.mode {
+            ThemeMode::Light => false,
+            ThemeMode::Dark => true,
+            ThemeMode::System => self.system_dark,
+        }
+    }
+
+    /// The theme currently in effect
+    pub fn theme(&self) -> &Theme {
+        if self.is_dark() {
+            &self.dark
+        } else {
+            &self.light
+        }
+    }
+}
+
+/// Provide the theme context for all child components
+///
+/// Loads the persisted mode, follows system preference changes and keeps
+/// the document's `dark` class in sync on the web. The app's root element
+/// should also carry the `dark` class so Tailwind `dark:` utilities work on
+/// platforms without a document to modify.
+pub fn use_theme_provider() -> Signal<ThemeState> {
+    let mut state = use_context_provider(|| {
+        Signal::new(ThemeState {
+            mode: platform::load_mode().unwrap_or_default(),
+            system_dark: platform::system_prefers_dark(),
+            ..Default::default()
+        })
+    });
+
+    platform::use_system_theme(move |dark| state.write().system_dark = dark);
+
+    use_effect(move || {
+        platform::apply_dark_class(state.read().is_dark());
+    });
+
+    state
+}
+
+/// Switch the theme mode and persist the choice
+///
+/// The system preference is kept up to date by the provider, so selecting
+/// [`ThemeMode::System`] uses it right away.
+pub fn set_theme_mode(state: &mut Signal<ThemeState>, mode: ThemeMode) {
+    state.write().mode = mode;
+    platform::store_mode(mode);
+}
+
+/// Read the theme currently in effect, falling back to the light theme when
+/// no provider is present
+pub fn use_theme() -> Theme {
+    match try_use_context::<Signal<ThemeState>>() {
+        Some(state) => state.read().theme().clone(),
+        None => Theme::light(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mode_round_trip() {
+        for mode in [ThemeMode::Light, ThemeMode::Dark, ThemeMode::System] {
+            assert_eq!(ThemeMode::parse(mode.as_str()), Some(mode));
+        }
+        assert_eq!(ThemeMode::parse("sepia"), None);
+    }
+
+    #[test]
+    fn test_system_mode_follows_preference() {
+        let mut state = ThemeState::default();
+        assert!(!state.is_dark());
+        state.system_dark = true;
+        assert!(state.theme().is_dark);
+        state.mode = ThemeMode::Light;
+        assert!(!state.theme().is_dark);
+    }
+}
@@ -0,0 +1,124 @@
+//! Desktop and mobile backend for the theme system
+//!
+//! The chosen mode is persisted in a small file in the app's config directory
+//! as reported by the platform. The system preference is the appearance of
+//! the window, which follows the operating system on desktop and mobile alike.
+//! Without a renderer feature there is no window and nothing is persisted.
+
+use super::ThemeMode;
+use std::fs;
+use std::path::PathBuf;
+
+#[cfg(feature = "desktop")]
+use dioxus::desktop as renderer;
+#[cfg(all(feature = "mobile", not(feature = "desktop")))]
+use dioxus::mobile as renderer;
+
+/// Directory name below the platform config directory
+#[cfg(all(
+    not(target_os = "android"),
+    any(feature = "desktop", feature = "mobile")
+))]
+const APP_DIR: &str = "radar-graph";
+/// File holding the persisted mode
+const THEME_FILE: &str = "theme";
+
+/// Config directory of the app, below the user's platform config directory
+#[cfg(all(
+    not(target_os = "android"),
+    any(feature = "desktop", feature = "mobile")
+))]
+fn app_dir() -> Option<PathBuf> {
+    Some(directories::BaseDirs::new()?.config_dir().join(APP_DIR))
+}
+
+/// Private files directory of the app, asked from the Android context so it
+/// is right for secondary users and work profiles as well
+#[cfg(all(target_os = "android", feature = "mobile"))]
+fn app_dir() -> Option<PathBuf> {
+    use jni::objects::{JObject, JString};
+    use jni::JavaVM;
+
+    let context = ndk_context::android_context();
+    // SAFETY: the pointers come from the running activity and stay valid for
+    // the lifetime of the app
+    let vm = unsafe { JavaVM::from_raw(context.vm().cast()) }.ok()?;
+    let activity = unsafe { JObject::from_raw(context.context().cast()) };
+
+    let mut env = vm.attach_current_thread().ok()?;
+    let dir = env
+        .call_method(&activity, "getFilesDir", "()Ljava/io/File;", &[])
+        .ok()?
+        .l()
+        .ok()?;
+    let path = env
+        .call_method(&dir, "getAbsolutePath", "()Ljava/lang/String;", &[])
+        .ok()?
+        .l()
+        .ok()?;
+    let path: String = env.get_string(&JString::from(path)).ok()?.into();
+    Some(PathBuf::from(path))
+}
+
+/// Nowhere to persist the mode without a renderer
+#[cfg(not(any(feature = "desktop", feature = "mobile")))]
+fn app_dir() -> Option<PathBuf> {
+    None
+}
+
+fn theme_file() -> Option<PathBuf> {
+    Some(app_dir()?.join(THEME_FILE))
+}
+
+/// Load the persisted mode from the config file
+pub fn load_mode() -> Option<ThemeMode> {
+    let content = fs::read_to_string(theme_file()?).ok()?;
+    ThemeMode::parse(&content)
+}
+
+/// Persist the mode to the config file, ignoring I/O errors
+pub fn store_mode(mode: ThemeMode) {
+    let Some(path) = theme_file() else {
+        return;
+    };
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    let _ = fs::write(path, mode.as_str());
+}
+
+/// Whether the window has a dark appearance
+#[cfg(any(feature = "desktop", feature = "mobile"))]
+pub fn system_prefers_dark() -> bool {
+    renderer::window().window.theme() == renderer::tao::window::Theme::Dark
+}
+
+/// Without a window the preference is unknown, so light is assumed
+#[cfg(not(any(feature = "desktop", feature = "mobile")))]
+pub fn system_prefers_dark() -> bool {
+    false
+}
+
+/// Nothing to do: the `dark` class is set on the app's root element instead
+pub fn apply_dark_class(_dark: bool) {}
+
+/// Call `on_change` whenever the appearance of the window changes
+#[cfg(any(feature = "desktop", feature = "mobile"))]
+pub fn use_system_theme(mut on_change: impl FnMut(bool) + 'static) {
+    use renderer::tao::event::{Event, WindowEvent};
+    use renderer::tao::window::Theme;
+
+    renderer::use_wry_event_handler(move |event, _| {
+        if let Event::WindowEvent {
+            event: WindowEvent::ThemeChanged(theme),
+            ..
+        } = event
+        {
+            on_change(*theme == Theme::Dark);
+        }
+    });
+}
+
+/// Without a window the preference never changes
+#[cfg(not(any(feature = "desktop", feature = "mobile")))]
+pub fn use_system_theme(_on_change: impl FnMut(bool) + 'static) {}
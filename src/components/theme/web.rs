@@ -0,0 +1,65 @@
+//! Web backend for the theme system using `localStorage` and `matchMedia`
+
+use super::ThemeMode;
+use dioxus::prelude::*;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// `localStorage` key of the persisted mode
+const STORAGE_KEY: &str = "theme";
+/// Media query matching a dark system preference
+const DARK_QUERY: &str = "(prefers-color-scheme: dark)";
+
+fn storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+fn dark_query() -> Option<web_sys::MediaQueryList> {
+    web_sys::window()?.match_media(DARK_QUERY).ok()?
+}
+
+/// Load the persisted mode; a missing entry means "follow the system"
+pub fn load_mode() -> Option<ThemeMode> {
+    let value = storage()?.get_item(STORAGE_KEY).ok()??;
+    ThemeMode::parse(&value)
+}
+
+/// Persist the mode, removing the entry for [`ThemeMode::System`]
+pub fn store_mode(mode: ThemeMode) {
+    let Some(storage) = storage() else {
+        return;
+    };
+    let _ = match mode {
+        ThemeMode::System => storage.remove_item(STORAGE_KEY),
+        _ => storage.set_item(STORAGE_KEY, mode.as_str()),
+    };
+}
+
+/// Whether the browser reports a dark system preference
+pub fn system_prefers_dark() -> bool {
+    dark_query().is_some_and(|query| query.matches())
+}
+
+/// Add or remove the `dark` class on the root element
+pub fn apply_dark_class(dark: bool) {
+    let root = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.document_element());
+    if let Some(root) = root {
+        let _ = root.class_list().toggle_with_force("dark", dark);
+    }
+}
+
+/// Call `on_change` whenever the system preference changes
+pub fn use_system_theme(mut on_change: impl FnMut(bool) + 'static) {
+    use_hook(move || {
+        let Some(query) = dark_query() else {
+            return;
+        };
+        let watched = query.clone();
+        let closure = Closure::<dyn FnMut()>::new(move || on_change(watched.matches()));
+        query.set_onchange(Some(closure.as_ref().unchecked_ref()));
+        // The listener lives as long as the page
+        closure.forget();
+    });
+}
@@ -1,80 +1,45 @@
+use crate::components::theme::{set_theme_mode, ThemeMode, ThemeState};
 use dioxus::prelude::*;
 
-/// ThemeButtons component for controlling light/dark mode theme switching.
+/// ThemeButtons component for switching between light, dark and system theme.
 ///
-/// This component is only available on web platforms (with the "web" feature flag) because:
-/// 1. It uses web-specific APIs like localStorage and document.documentElement
-/// 2. It uses js_sys::eval() which requires WASM and browser environment
-/// 3. These APIs are not available in desktop or other non-web environments
-///
-/// For desktop builds, an alternative theming approach would be needed,
-/// but for now we provide an empty component to maintain API compatibility.
-#[cfg(feature = "web")]
+/// The buttons switch the [`ThemeMode`] of the theme context provided by
+/// [`crate::components::theme::use_theme_provider`]. Persisting the choice and
+/// detecting the system preference is handled by the theme module for each
+/// platform: `localStorage` on the web, a config file on desktop and mobile.
 #[component]
 pub fn ThemeButtons() -> Element {
+    let state = use_context::<Signal<ThemeState>>();
+    let current_mode = state.read().mode;
+
+    let buttons = [
+        (ThemeMode::Light, "Light mode", "☀️"),
+        (ThemeMode::System, "System preference", "🌓"),
+        (ThemeMode::Dark, "Dark mode", "🌙"),
+    ]
+    .into_iter()
+    .map(|(mode, title, icon)| {
+        let mut state = state;
+        let active_class = if mode == current_mode {
+            "bg-gray-200 dark:bg-gray-700"
+        } else {
+            ""
+        };
+
+        rsx! {
+            button {
+                class: "p-2 rounded-md hover:bg-gray-200 dark:hover:bg-gray-700 transition-colors {active_class}",
+                onclick: move |_| set_theme_mode(&mut state, mode),
+                title: "{title}",
+                "{icon}"
+            }
+        }
+    });
+
     rsx! {
         div {
             class: "flex gap-2 items-center",
-            button {
-                class: "p-2 rounded-md hover:bg-gray-200 dark:hover:bg-gray-700 transition-colors",
-                onclick: move |_| {
-                    let script = r#"
-                        localStorage.theme = 'light'; 
-                        document.documentElement.classList.remove('dark');
-                        console.log('Light mode activated');
-                    "#;
-                    let _ = js_sys::eval(script);
-                },
-                title: "Light mode",
-                "☀️"
-            }
-            button {
-                class: "p-2 rounded-md hover:bg-gray-200 dark:hover:bg-gray-700 transition-colors",
-                onclick: move |_| {
-                    let script = r#"
-                        localStorage.removeItem('theme'); 
-                        if (window.matchMedia('(prefers-color-scheme: dark)').matches) { 
-                            document.documentElement.classList.add('dark');
-                            console.log('System theme (dark) activated'); 
-                        } else { 
-                            document.documentElement.classList.remove('dark');
-                            console.log('System theme (light) activated');
-                        }
-                    "#;
-                    let _ = js_sys::eval(script);
-                },
-                title: "System preference",
-                "🌓"
-            }
-            button {
-                class: "p-2 rounded-md hover:bg-gray-200 dark:hover:bg-gray-700 transition-colors",
-                onclick: move |_| {
-                    let script = r#"
-                        localStorage.theme = 'dark'; 
-                        document.documentElement.classList.add('dark');
-                        console.log('Dark mode activated');
-                    "#;
-                    let _ = js_sys::eval(script);
-                },
-                title: "Dark mode",
-                "🌙"
-            }
+            {buttons}
         }
     }
 }
-
-/// Empty ThemeButtons component for non-web platforms.
-///
-/// This version is provided for non-web builds (desktop, mobile) because:
-/// 1. Web-specific APIs like localStorage and document are not available
-/// 2. js_sys::eval() would panic on non-web targets as seen in the error:
-///    "cannot call wasm-bindgen imported functions on non-wasm targets"
-/// 3. Provides API compatibility so the same component can be used regardless of target
-///
-/// TODO: Implement proper theme switching for desktop/mobile platforms if needed
-#[cfg(not(feature = "web"))]
-#[component]
-pub fn ThemeButtons() -> Element {
-    // Return an empty element for non-web platforms
-    rsx! { "" }
-}
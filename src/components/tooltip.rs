@@ -1,11 +1,150 @@
+use crate::components::radar::CurveId;
+use crate::components::theme::use_theme;
+use crate::components::utils::estimate_width;
 use dioxus::prelude::*;
 
+/// Distance between the data point and the tip of the arrow
+const GAP: f32 = 4.0;
+/// Length of the arrow
+const ARROW: f32 = 6.0;
+/// Height of a single-line tooltip
+pub const TOOLTIP_HEIGHT: f32 = 20.0;
+/// Height added per note line
+pub const NOTE_HEIGHT: f32 = 14.0;
+
+/// Structured content of a data point tooltip
+///
+/// Passed to custom tooltip renderers, so they can look up extra context
+/// such as previous values or a baseline by curve id and point index.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TooltipContent {
+    /// Stable identifier of the curve
+    pub curve_id: CurveId,
+    /// Display name of the curve
+    pub curve_name: String,
+    /// Index of the data point within the curve
+    pub point_index: usize,
+    /// Name of the axis the data point sits on
+    pub axis: String,
+    /// Current value of the data point
+    pub value: f32,
+    /// The value as it is displayed
+    pub formatted_value: String,
+    /// Additional lines shown below the value
+    pub notes: Vec<String>,
+}
+
+impl TooltipContent {
+    /// Replace the value, keeping the formatted value in sync
+    pub fn set_value(&mut self, value: f32) {
+        self.value = value;
+        self.formatted_value = format_value(value);
+    }
+
+    /// The main line of the default tooltip, e.g. `Speed: 70`
+    pub fn summary(&self) -> String {
+        format!("{}: {}", self.axis, self.formatted_value)
+    }
+}
+
+/// Format a value for display, with at most two decimals
+pub fn format_value(value: f32) -> String {
+    let rounded = (value * 100.0).round() / 100.0;
+    format!("{rounded}")
+}
+
+/// Side of the data point the tooltip box is drawn on
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TooltipSide {
+    Above,
+    Below,
+    Left,
+    Right,
+}
+
+/// Position of a tooltip box and its arrow
+#[derive(Clone, Debug, PartialEq)]
+pub struct TooltipLayout {
+    /// Left edge of the box
+    pub left: f32,
+    /// Top edge of the box
+    pub top: f32,
+    pub width: f32,
+    pub height: f32,
+    pub side: TooltipSide,
+    /// SVG `points` of the triangle pointing at the data point
+    pub arrow: String,
+}
+
+/// Place a `width` × `height` tooltip for the point `(x, y)` inside a
+/// drawing area of `bounds` (width, height).
+///
+/// The tooltip goes above the point if it fits, otherwise below, to the
+/// right or to the left, and is shifted along that edge to stay inside.
+pub fn tooltip_layout(
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    bounds: (f32, f32),
+) -> TooltipLayout {
+    let (bounds_width, bounds_height) = bounds;
+    let offset = GAP + ARROW;
+    let clamp = |value: f32, size: f32, max: f32| value.min(max - size).max(0.0);
+    let centered_left = clamp(x - width / 2.0, width, bounds_width);
+    let centered_top = clamp(y - height / 2.0, height, bounds_height);
+
+    let candidates = [
+        (TooltipSide::Above, centered_left, y - offset - height),
+        (TooltipSide::Below, centered_left, y + offset),
+        (TooltipSide::Right, x + offset, centered_top),
+        (TooltipSide::Left, x - offset - width, centered_top),
+    ];
+    let fits = |(_, left, top): &(TooltipSide, f32, f32)| {
+        *left >= 0.0 && *top >= 0.0 && left + width <= bounds_width && top + height <= bounds_height
+    };
+    let (side, left, top) = candidates
+        .iter()
+        .copied()
+        .find(fits)
+        .unwrap_or(candidates[0]);
+
+    // The arrow base stays on the box edge even when the box was shifted
+    let base_x = x.clamp(left + ARROW, (left + width - ARROW).max(left + ARROW));
+    let base_y = y.clamp(top + ARROW, (top + height - ARROW).max(top + ARROW));
+    let (first, second, tip) = match side {
+        TooltipSide::Above => {
+            let edge = top + height;
+            ((base_x - ARROW, edge), (base_x + ARROW, edge), (x, y - GAP))
+        }
+        TooltipSide::Below => ((base_x - ARROW, top), (base_x + ARROW, top), (x, y + GAP)),
+        TooltipSide::Right => ((left, base_y - ARROW), (left, base_y + ARROW), (x + GAP, y)),
+        TooltipSide::Left => {
+            let edge = left + width;
+            ((edge, base_y - ARROW), (edge, base_y + ARROW), (x - GAP, y))
+        }
+    };
+    let arrow = format!(
+        "{},{} {},{} {},{}",
+        first.0, first.1, second.0, second.1, tip.0, tip.1
+    );
+
+    TooltipLayout {
+        left,
+        top,
+        width,
+        height,
+        side,
+        arrow,
+    }
+}
+
 /// Props for the Tooltip component
 #[derive(Props, PartialEq, Clone)]
 pub struct TooltipProps {
-    /// The x-coordinate for the tooltip
+    /// The x-coordinate of the point the tooltip belongs to
     pub x: f32,
-    /// The y-coordinate for the tooltip
+    /// The y-coordinate of the point the tooltip belongs to
     pub y: f32,
     /// Content to display in the tooltip
     pub content: String,
@@ -15,18 +154,28 @@ pub struct TooltipProps {
     /// Whether the tooltip is pinned (stays visible when not hovering)
     #[props(default = false)]
     pub pinned: bool,
+    /// Width and height of the drawing area the tooltip has to stay inside
+    #[props(optional)]
+    pub bounds: Option<(f32, f32)>,
 }
 
 /// A simple tooltip component for displaying information on hover
 #[component]
 pub fn Tooltip(props: TooltipProps) -> Element {
+    let theme = use_theme();
+
     if !props.visible {
         return rsx! {};
     }
 
-    // Position the tooltip slightly above the point
-    let tooltip_x = props.x;
-    let tooltip_y = props.y - 15.0;
+    // Size the box to the content and keep it inside the drawing area
+    let layout = tooltip_layout(
+        props.x,
+        props.y,
+        estimate_width(&props.content, 12.0) + 16.0,
+        TOOLTIP_HEIGHT,
+        props.bounds.unwrap_or((f32::INFINITY, f32::INFINITY)),
+    );
 
     // Add class for pinned state
     let pinned_class = if props.pinned { "tooltip-pinned" } else { "" };
@@ -35,25 +184,67 @@ pub fn Tooltip(props: TooltipProps) -> Element {
         g {
             class: "tooltip {pinned_class}",
             rect {
-                x: "{tooltip_x - 60.0}",
-                y: "{tooltip_y - 25.0}",
-                width: "120",
-                height: "20",
+                x: "{layout.left}",
+                y: "{layout.top}",
+                width: "{layout.width}",
+                height: "{layout.height}",
                 rx: "3",
                 ry: "3",
-                fill: "#333333",
-                class: "dark:fill-gray-800",
-                opacity: "0.8",
+                fill: "{theme.tooltip_background}",
+                opacity: "{theme.tooltip_opacity}",
+            }
+            polygon {
+                points: "{layout.arrow}",
+                fill: "{theme.tooltip_background}",
+                opacity: "{theme.tooltip_opacity}",
             }
             text {
-                x: "{tooltip_x}",
-                y: "{tooltip_y - 12.0}",
-                fill: "#ffffff",
-                class: "dark:fill-white",
+                x: "{layout.left + layout.width / 2.0}",
+                y: "{layout.top + layout.height / 2.0}",
+                fill: "{theme.tooltip_text}",
                 "text-anchor": "middle",
+                "dominant-baseline": "middle",
                 "font-size": "12",
                 "{props.content}"
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_keeps_colons_in_axis() {
+        let mut content = TooltipContent {
+            curve_id: CurveId::from("a"),
+            curve_name: "A".to_string(),
+            point_index: 0,
+            axis: "Latency: p99".to_string(),
+            value: 0.0,
+            formatted_value: String::new(),
+            notes: Vec::new(),
+        };
+        content.set_value(12.3456);
+        assert_eq!(content.summary(), "Latency: p99: 12.35");
+    }
+
+    #[test]
+    fn test_tooltip_flips_near_edges() {
+        let bounds = (500.0, 500.0);
+        assert_eq!(
+            tooltip_layout(250.0, 250.0, 100.0, 20.0, bounds).side,
+            TooltipSide::Above
+        );
+        assert_eq!(
+            tooltip_layout(250.0, 10.0, 100.0, 20.0, bounds).side,
+            TooltipSide::Below
+        );
+
+        // Shifted sideways, but still above the point
+        let layout = tooltip_layout(490.0, 250.0, 100.0, 20.0, bounds);
+        assert_eq!(layout.side, TooltipSide::Above);
+        assert_eq!(layout.left, 400.0);
+    }
+}
@@ -1,3 +1,5 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 /// Converts polar coordinates to Cartesian coordinates
 pub fn polar_to_cartesian(
     radius: f32,
@@ -10,6 +12,26 @@ pub fn polar_to_cartesian(
     (x, y)
 }
 
+/// Average glyph width relative to the font size
+const GLYPH_WIDTH: f32 = 0.6;
+
+/// Estimated width of `text` at `font_size`.
+///
+/// SVG can't measure text before it is rendered, so sizes are estimated from
+/// the character count, which is close enough for boxes around short labels.
+pub fn estimate_width(text: &str, font_size: f32) -> f32 {
+    text.chars().count() as f32 * font_size * GLYPH_WIDTH
+}
+
+/// Returns a document-wide unique id with the given prefix.
+///
+/// Used for SVG `<defs>` that are referenced by id, so several charts on the
+/// same page don't pick up each other's definitions.
+pub fn unique_id(prefix: &str) -> String {
+    static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+    format!("{prefix}-{}", NEXT_ID.fetch_add(1, Ordering::Relaxed))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
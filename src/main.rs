@@ -2,8 +2,19 @@
 // need dioxus
 use dioxus::prelude::*;
 
+use components::color::Color;
+use components::palette::Palette;
+use components::radar::container::graph::radar::axis::AxisLabelMode;
+use components::radar::container::graph::radar::geometry::Direction;
+use components::radar::container::graph::radar::labels::ValueLabels;
+use components::radar::container::graph::radar::style::{
+    CurveFill, CurveStyle, DashPattern, MarkerShape,
+};
+use components::radar::container::legend::LegendPlacement;
+use components::radar::container::stats::StatColumn;
 use components::radar::container::RadarContainer;
 use components::radar::{DataPoint, RadarCurve};
+use components::theme::use_theme_provider;
 use components::theme_buttons::ThemeButtons;
 
 /// Define a components module that contains all shared components for our app.
@@ -16,6 +27,46 @@ const FAVICON: Asset = asset!("/assets/favicon.ico");
 const MAIN_CSS: Asset = asset!("/assets/styling/main.css");
 const TAILWIND_CSS: Asset = asset!("/assets/tailwind.css");
 
+// Choices offered by the settings row above the chart
+const PALETTES: [(&str, Palette); 4] = [
+    ("Categorical", Palette::Categorical),
+    ("Okabe-Ito", Palette::OkabeIto),
+    ("Tableau", Palette::Tableau),
+    ("High contrast", Palette::HighContrast),
+];
+const LEGEND_PLACEMENTS: [(&str, LegendPlacement); 6] = [
+    ("Right", LegendPlacement::Right),
+    ("Left", LegendPlacement::Left),
+    ("Top", LegendPlacement::Top),
+    ("Bottom", LegendPlacement::Bottom),
+    ("Overlay", LegendPlacement::Overlay),
+    ("Hidden", LegendPlacement::Hidden),
+];
+const VALUE_LABELS: [(&str, ValueLabels); 4] = [
+    ("Tooltips only", ValueLabels::None),
+    ("All", ValueLabels::All),
+    ("Focused curve", ValueLabels::Focused),
+    ("Min and max", ValueLabels::MinMax),
+];
+const DIRECTIONS: [(&str, Direction); 2] = [
+    ("Clockwise", Direction::Clockwise),
+    ("Counterclockwise", Direction::CounterClockwise),
+];
+const LABEL_MODES: [(&str, AxisLabelMode); 4] = [
+    ("Automatic", AxisLabelMode::Auto),
+    ("Horizontal", AxisLabelMode::Horizontal),
+    ("Rotated", AxisLabelMode::Rotated),
+    ("Thinned", AxisLabelMode::Thinned),
+];
+const OUTLINES: [&str; 4] = ["Solid", "Dashed", "Dotted", "Dash-dot"];
+const MARKERS: [(&str, MarkerShape); 5] = [
+    ("Circles", MarkerShape::Circle),
+    ("Squares", MarkerShape::Square),
+    ("Triangles", MarkerShape::Triangle),
+    ("Diamonds", MarkerShape::Diamond),
+    ("No markers", MarkerShape::None),
+];
+
 fn main() {
     // The `launch` function is the main entry point for a dioxus app. It takes a component and renders it with the platform feature
     // you have enabled
@@ -37,98 +88,77 @@ fn App() -> Element {
         "Durability".to_string(),
     ];
 
-    let curve1 = RadarCurve {
-        name: "Model A".to_string(),
-        color: "#3366CC".to_string(),
-        data_points: vec![
-            DataPoint {
-                value: 70.0,
-                label: "Speed".to_string(),
-            },
-            DataPoint {
-                value: 85.0,
-                label: "Power".to_string(),
-            },
-            DataPoint {
-                value: 65.0,
-                label: "Accuracy".to_string(),
-            },
-            DataPoint {
-                value: 90.0,
-                label: "Range".to_string(),
-            },
-            DataPoint {
-                value: 75.0,
-                label: "Durability".to_string(),
-            },
+    // Indices into the choices above
+    let palette = use_signal(|| 0);
+    let outline = use_signal(|| 0);
+    let markers = use_signal(|| 0);
+    let label_mode = use_signal(|| 0);
+    let direction = use_signal(|| 0);
+    let value_labels = use_signal(|| 0);
+    let legend_placement = use_signal(|| 0);
+
+    let curve1 = RadarCurve::new(
+        "Model A",
+        vec![
+            DataPoint::new("Speed", 70.0),
+            DataPoint::new("Power", 85.0),
+            DataPoint::new("Accuracy", 65.0),
+            DataPoint::new("Range", 90.0).with_note("Measured with a full battery"),
+            DataPoint::new("Durability", 75.0),
         ],
-    };
+    )
+    .with_id("model-a")
+    .with_group("Prototypes");
 
-    let curve2 = RadarCurve {
-        name: "Model B".to_string(),
-        color: "#DC3912".to_string(),
-        data_points: vec![
-            DataPoint {
-                value: 80.0,
-                label: "Speed".to_string(),
-            },
-            DataPoint {
-                value: 65.0,
-                label: "Power".to_string(),
-            },
-            DataPoint {
-                value: 90.0,
-                label: "Accuracy".to_string(),
-            },
-            DataPoint {
-                value: 70.0,
-                label: "Range".to_string(),
-            },
-            DataPoint {
-                value: 85.0,
-                label: "Durability".to_string(),
-            },
+    let curve2 = RadarCurve::new(
+        "Model B",
+        vec![
+            DataPoint::new("Speed", 80.0),
+            DataPoint::new("Power", 65.0),
+            DataPoint::new("Accuracy", 90.0),
+            DataPoint::new("Range", 70.0),
+            DataPoint::new("Durability", 85.0),
         ],
-    };
+    )
+    .with_id("model-b")
+    .with_group("Prototypes")
+    .with_style(CurveStyle {
+        dash: match outline() {
+            1 => DashPattern::Dashed,
+            2 => DashPattern::Dotted,
+            3 => DashPattern::Custom(vec![8.0, 3.0, 2.0, 3.0]),
+            _ => DashPattern::Solid,
+        },
+        fill: CurveFill::Opacity(0.15),
+        marker: MARKERS[markers()].1,
+        ..Default::default()
+    })
+    .with_color_str("#DC3912")
+    .expect("the demo color is valid");
 
-    // The `rsx!` macro lets us define HTML inside of rust. It expands to an Element with all of our HTML inside.
-    // Initialize dark mode on load - only for web platform
-    #[cfg(feature = "web")]
-    use_effect(|| {
-        let script = r#"
-        // Check for color theme preference and apply it
-        function applyTheme() {
-            if (localStorage.theme === 'dark' || (!('theme' in localStorage) && window.matchMedia('(prefers-color-scheme: dark)').matches)) {
-                document.documentElement.classList.add('dark');
-                console.log('Dark theme applied');
-            } else {
-                document.documentElement.classList.remove('dark');
-                console.log('Light theme applied');
-            }
-        }
-        
-        // Apply theme immediately
-        applyTheme();
-        
-        // Listen for system preference changes
-        window.matchMedia('(prefers-color-scheme: dark)').addEventListener('change', event => {
-            if (!localStorage.theme) {
-                if (event.matches) {
-                    document.documentElement.classList.add('dark');
-                    console.log('System theme changed to dark');
-                } else {
-                    document.documentElement.classList.remove('dark');
-                    console.log('System theme changed to light');
-                }
-            }
-        });
-        "#;
+    // Fixed reference the models are compared against
+    let target = RadarCurve::new(
+        "Target",
+        vec![
+            DataPoint::new("Speed", 75.0),
+            DataPoint::new("Power", 75.0),
+            DataPoint::new("Accuracy", 80.0),
+            DataPoint::new("Range", 80.0),
+            DataPoint::new("Durability", 80.0),
+        ],
+    )
+    .with_color(Color::from_hex(0x888888))
+    .with_style(CurveStyle::baseline())
+    .with_editable(false);
 
-        let _ = web_sys::window().map(|_| {
-            let _ = js_sys::eval(script);
-        });
-    });
+    // Provide the theme to all components. This loads the persisted choice and follows the
+    // system preference on every platform
+    let theme_state = use_theme_provider();
+    let theme = theme_state.read().theme().clone();
+    // The `dark` class on our root element enables Tailwind `dark:` utilities on every platform
+    let dark_class = if theme.is_dark { "dark" } else { "" };
 
+    // The `rsx!` macro lets us define HTML inside of rust. It expands to an Element with all of our HTML inside.
     rsx! {
         // In addition to element and text (which we will see later), rsx can contain other components. In this case,
         // we are using the `document::Link` component to add a link to our favicon and main CSS file into the head of our app.
@@ -137,7 +167,8 @@ fn App() -> Element {
         document::Link { rel: "stylesheet", href: TAILWIND_CSS }
 
         div {
-            class: "container mx-auto px-4 py-8 bg-background dark:bg-gray-900 text-text dark:text-white min-h-screen transition-colors duration-300",
+            class: "{dark_class} container mx-auto px-4 py-8 min-h-screen transition-colors duration-300",
+            style: "background-color: {theme.background}; color: {theme.text};",
             div {
                 class: "flex justify-between items-center mb-6",
                 h1 {
@@ -145,18 +176,68 @@ fn App() -> Element {
                     "Radar Graph Demo"
                 }
 
-                // Theme buttons component will be empty on non-web platforms
+                // Light/dark/system theme selection, persisted per platform
                 ThemeButtons {}
             }
+            // Settings that switch between the modes of the chart
+            div {
+                class: "flex flex-wrap items-center gap-4 mb-4 text-sm",
+                Setting { label: "Palette", options: PALETTES.map(|(name, _)| name).to_vec(), selected: palette }
+                // Preview of the colors the palette assigns
+                div {
+                    class: "flex gap-1",
+                    for (index, color) in PALETTES[palette()].1.colors(theme.is_dark).into_iter().enumerate() {
+                        span {
+                            key: "{index}",
+                            class: "w-3 h-3 rounded-sm",
+                            style: "background-color: {color};",
+                        }
+                    }
+                }
+                Setting { label: "Model B outline", options: OUTLINES.to_vec(), selected: outline }
+                Setting { label: "Model B markers", options: MARKERS.map(|(name, _)| name).to_vec(), selected: markers }
+                Setting { label: "Axis labels", options: LABEL_MODES.map(|(name, _)| name).to_vec(), selected: label_mode }
+                Setting { label: "Direction", options: DIRECTIONS.map(|(name, _)| name).to_vec(), selected: direction }
+                Setting { label: "Value labels", options: VALUE_LABELS.map(|(name, _)| name).to_vec(), selected: value_labels }
+                Setting { label: "Legend", options: LEGEND_PLACEMENTS.map(|(name, _)| name).to_vec(), selected: legend_placement }
+            }
             // Responsive radar graph container
             div {
                 class: "container mx-auto",
                 RadarContainer {
                     axes: axes,
-                    curves: vec![curve1, curve2],
+                    curves: vec![curve1, curve2, target],
                     max_value: 100.0,
                     width: 600,
                     height: 500,
+                    palette: PALETTES[palette()].1,
+                    axis_label_mode: LABEL_MODES[label_mode()].1,
+                    direction: DIRECTIONS[direction()].1,
+                    value_labels: VALUE_LABELS[value_labels()].1,
+                    legend_stats: vec![StatColumn::Mean, StatColumn::Sum, StatColumn::Area, StatColumn::Score],
+                    legend_placement: LEGEND_PLACEMENTS[legend_placement()].1,
+                }
+            }
+        }
+    }
+}
+
+/// A labeled drop-down that stores the index of the chosen option
+#[component]
+fn Setting(label: &'static str, options: Vec<&'static str>, selected: Signal<usize>) -> Element {
+    rsx! {
+        label {
+            class: "flex items-center gap-2",
+            "{label}"
+            select {
+                class: "px-2 py-1 rounded border bg-transparent",
+                onchange: move |evt| {
+                    if let Ok(index) = evt.value().parse() {
+                        selected.set(index);
+                    }
+                },
+                for (index, option) in options.iter().enumerate() {
+                    option { key: "{index}", value: "{index}", selected: index == selected(), "{option}" }
                 }
             }
         }